@@ -2,7 +2,7 @@ mod inner;
 
 #[cfg(no_std)]
 use core::{
-    any::Any,
+    any::{Any, TypeId},
     fmt,
     ops::Deref,
     mem::ManuallyDrop
@@ -10,13 +10,21 @@ use core::{
 
 #[cfg(not(no_std))]
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     fmt,
     ops::Deref,
     mem::ManuallyDrop
 };
 
-use super::{OwnedArgument, discriminant::Discriminant};
+use super::{
+    OwnedArgument,
+    SliceRef,
+    VariantHandle,
+    coerce::{coerce_numeric, Coercion, CoerceError},
+    discriminant::Discriminant
+};
+
+use crate::{ConversionRegistry, Converted, DerefArgument};
 
 use inner::{RawArgument, InnerArgument};
 
@@ -43,7 +51,7 @@ impl fmt::Debug for Argument<'_>
     {
         match self.discriminant()
         {
-            Discriminant::Borrowed =>
+            _ if self.is_borrowed() =>
             {
                 f.debug_tuple("Argument::Borrowed")
                  .field(&self.inner.to_ref())
@@ -90,14 +98,11 @@ impl<'a> Clone for Argument<'a>
             }
             _ =>
             {
+                // Borrowed slices stay borrowed, while everything else gets cloned.
                 let owned =
-                {
-                    let raw = &raw const self.inner;
-                    unsafe
-                    {
-                        (*raw.cast::<OwnedArgument>()).clone()
-                    }
-                };
+                self.inner
+                    .handle()
+                    .clone_borrowed();
                 
                 InnerArgument::new_owned(owned)
             }
@@ -139,6 +144,9 @@ impl Argument<'_>
     }
     
     /// Checks if the argument is owned.
+    ///
+    /// Arguments created through new_borrowed_str or new_borrowed_slice are not
+    /// owned, even though they do not hold an `&dyn Any`.
     #[inline(always)]
     pub fn is_owned(&self) -> bool
     {
@@ -147,6 +155,8 @@ impl Argument<'_>
     }
     
     /// Checks if the argument is borrowed.
+    ///
+    /// This includes arguments created through new_borrowed_str or new_borrowed_slice.
     #[inline(always)]
     pub fn is_borrowed(&self) -> bool
     {
@@ -154,10 +164,25 @@ impl Argument<'_>
             .is_borrowed()
     }
     
+    /// Returns the TypeId of the argument's value.
+    ///
+    /// This is the TypeId of the dereferenced contents, except for borrowed str
+    /// and `[T]` slices, which report the String and `Vec<T>` they promote into.
+    /// Said slices cannot be read through downcast_ref, but downcast_cloned,
+    /// downcast_owned, to_mut and the coercions all see them as such.
+    #[inline(always)]
+    pub fn value_type_id(&self) -> TypeId
+    {
+        self.inner
+            .handle()
+            .value_type_id()
+    }
+    
     /// Returns a mutable reference to the item itself.
     ///
     /// If the inner contents are borrowed, this creates a new
     /// owned instance first before returning the reference itself.
+    /// Borrowed str and `[T]` slices are promoted into String and `Vec<T>`.
     #[inline(always)]
     pub fn to_mut(&mut self) -> &mut dyn Any
     {
@@ -173,33 +198,27 @@ impl Argument<'_>
     }
     
     /// Clones the inner contents of the object, returning an owned argument.
+    ///
+    /// Borrowed str and `[T]` slices are promoted into String and `Vec<T>`.
     #[inline(always)]
     pub fn to_owned(&self) -> Self
     {
-        match self.discriminant()
+        let owned =
+        self.inner
+            .handle()
+            .clone_object();
+        
+        Self
         {
-            Discriminant::Borrowed =>
-            {
-                let ref_ =
-                unsafe
-                {
-                    self.inner
-                        .ref_unchecked()
-                };
-                
-                let owned = ref_.clone_object();
-                
-                Self
-                {
-                    inner: InnerArgument::new_owned(owned)
-                }
-            }
-            _ => self.clone()
+            inner: InnerArgument::new_owned(owned)
         }
     }
     
     /// Downcasts an owned argument into type T, returning a result.
     ///
+    /// Borrowed str and `[T]` slices are promoted into String and `Vec<T>`,
+    /// as they cannot be handed back otherwise.
+    ///
     /// # Return values
     /// Ok(T): The argument gets consumed and returns the inner contents.
     /// Err(Self): Either the argument is not of type T or the argument itself is not owned.
@@ -217,6 +236,19 @@ impl Argument<'_>
                 Ok(owned.downcast_owned_unchecked())
             }
             
+            RawArgument::Owned(o)
+            if o.raw_ref().promoted_type_id() == Some(TypeId::of::<T>()) =>
+            {
+                let promoted = o.raw_ref().clone_object();
+                
+                drop(o);
+                
+                unsafe
+                {
+                    Ok(promoted.downcast_owned_unchecked())
+                }
+            }
+            
             RawArgument::Owned(o)
             =>
             Err(Self { inner: InnerArgument::new_owned(o) }),
@@ -264,18 +296,159 @@ impl Argument<'_>
     
     /// Downcasts the argument into a cloned object of type T.
     ///
+    /// Borrowed str and `[T]` slices are promoted into String and `Vec<T>`.
+    ///
     /// Returns None if the object's type is not T.
     #[inline(always)]
     pub fn downcast_cloned<T>(&self) -> Option<T>
     where
         T: Any + Clone
     {
-        #[allow(clippy::manual_map)]
         match self.downcast_ref::<T>()
         {
             Some(t) => Some(t.clone()),
-            None => None
+            None => self.promote::<T>()
+        }
+    }
+    
+    /// Promotes a borrowed slice into T, as to_owned would.
+    ///
+    /// Returns None if the argument is not a borrowed slice promoting into T.
+    #[inline(always)]
+    fn promote<T>(&self) -> Option<T>
+    where
+        T: Any + Clone
+    {
+        let handle = self.inner.handle();
+        
+        if handle.promoted_type_id() != Some(TypeId::of::<T>())
+        {
+            return None;
+        }
+        
+        let promoted = handle.clone_object();
+        
+        unsafe
+        {
+            Some(promoted.downcast_owned_unchecked())
+        }
+    }
+    
+    /// Coerces the argument into a cloned object of type T.
+    ///
+    /// An exact match is tried first. Otherwise, this falls back to the lossless
+    /// conversions between the integer and float primitives, such as i32 into i64
+    /// or f32 into f64.
+    ///
+    /// Returns None if neither succeeds.
+    #[inline(always)]
+    pub fn coerce<T>(&self) -> Option<T>
+    where
+        T: Any + Clone
+    {
+        match self.downcast_cloned::<T>()
+        {
+            Some(t) => Some(t),
+            None => coerce_numeric(&**self, false).ok()
+        }
+    }
+    
+    /// Coerces the argument into a cloned object of type T, allowing lossy conversions.
+    ///
+    /// Besides the conversions done by coerce, any integer or float primitive converts
+    /// into any other. Floats are truncated when converted into integers.
+    ///
+    /// # Return values
+    /// Ok(T): The argument is either T, or has been converted into T.
+    /// Err(CoerceError::Overflow): The value is outside of T's range, or is NaN.
+    /// Err(CoerceError::Mismatch): There is no conversion into T.
+    #[inline(always)]
+    pub fn coerce_lossy<T>(&self) -> Result<T, CoerceError>
+    where
+        T: Any + Clone
+    {
+        match self.downcast_cloned::<T>()
+        {
+            Some(t) => Ok(t),
+            None => coerce_numeric(&**self, true)
+        }
+    }
+    
+    /// Coerces the argument into a cloned object of type T, following a coercion mode.
+    ///
+    /// Returns None if the argument does not convert into T under that mode,
+    /// including values that overflow under Coercion::Lossy.
+    #[inline(always)]
+    pub fn coerce_with<T>(&self, coercion: Coercion) -> Option<T>
+    where
+        T: Any + Clone
+    {
+        match coercion
+        {
+            Coercion::Exact => self.downcast_cloned::<T>(),
+            Coercion::Lossless => self.coerce::<T>(),
+            Coercion::Lossy => self.coerce_lossy::<T>().ok()
+        }
+    }
+    
+    /// Converts the argument into T through a conversion registry.
+    ///
+    /// An exact match is tried first, followed by the registered conversions, which
+    /// may be chained. The returned value lists the conversions that were applied.
+    ///
+    /// Borrowed str and `[T]` slices are converted as String and `Vec<T>`.
+    ///
+    /// Returns None if there is no successful conversion.
+    #[inline(always)]
+    pub fn convert<T>(&self, registry: &ConversionRegistry) -> Option<Converted<T>>
+    where
+        T: Any + Clone
+    {
+        if self.inner.is_borrowed_slice()
+        {
+            let promoted = self.inner.handle().clone_object();
+            
+            return registry.convert(&*promoted);
         }
+        
+        registry.convert(&**self)
+    }
+    
+    /// Downcasts a reference to the argument into T, seeing through pointer wrappers.
+    ///
+    /// Besides T itself, this reads T out of `&'static T`, `Box<T>`, `Rc<T>`,
+    /// `Arc<T>` and `Cow<'static, T>`. It also reads str out of String and
+    /// `[T]` out of `Vec<T>`. Refer to DerefArgument for the full list.
+    ///
+    /// Returns None if the argument is none of those.
+    #[inline(always)]
+    pub fn downcast_deref<T>(&self) -> Option<&T>
+    where
+        T: DerefArgument + ?Sized
+    {
+        T::deref_any(&**self)
+    }
+    
+    /// Downcasts a reference to the argument into str.
+    ///
+    /// This reads borrowed string slices, as well as every type
+    /// accepted by `downcast_deref::<str>()`, such as String.
+    #[inline(always)]
+    pub fn downcast_str(&self) -> Option<&str>
+    {
+        self.downcast_deref::<str>()
+    }
+    
+    /// Downcasts a reference to the argument into `[T]`.
+    ///
+    /// This reads borrowed slices, as well as every type
+    /// accepted by `downcast_deref::<[T]>()`, such as `Vec<T>`.
+    #[inline(always)]
+    pub fn downcast_slice<T>(&self) -> Option<&[T]>
+    where
+        T: Any + Clone
+    {
+        self.downcast_deref::<[T]>()
     }
     
     /// Binding to downcast a reference to T without checks.
@@ -287,7 +460,7 @@ impl Argument<'_>
     /// # Safety
     /// Assumes that the contents are of type T.
     #[inline(always)]
-    unsafe fn downcast_ref_unchecked<T>(&self) -> &T
+    pub(crate) unsafe fn downcast_ref_unchecked<T>(&self) -> &T
     where
         T: Any + Clone
     {
@@ -331,6 +504,55 @@ impl<'a> Argument<'a>
         }
     }
     
+    /// Creates a borrowed argument from a variant handle.
+    #[inline(always)]
+    pub(crate) fn from_handle(handle: &'a dyn VariantHandle) -> Self
+    {
+        Self
+        {
+            inner: InnerArgument::new_ref(handle)
+        }
+    }
+    
+    /// Acquires a handle to the underlying object.
+    #[inline(always)]
+    pub(crate) fn handle(&self) -> &dyn VariantHandle
+    {
+        self.inner.handle()
+    }
+    
+    /// Creates a borrowed argument of a string slice.
+    ///
+    /// The slice is not copied. Writing to the argument through to_mut,
+    /// or calling to_owned, promotes it into a String.
+    #[inline(always)]
+    pub fn new_borrowed_str(item: &'a str) -> Self
+    {
+        let handle = OwnedArgument::from_handle(SliceRef::new(item));
+        
+        Self
+        {
+            inner: InnerArgument::new_owned(handle)
+        }
+    }
+    
+    /// Creates a borrowed argument of a slice.
+    ///
+    /// The slice is not copied. Writing to the argument through to_mut,
+    /// or calling to_owned, promotes it into a `Vec<T>`.
+    #[inline(always)]
+    pub fn new_borrowed_slice<T>(item: &'a [T]) -> Self
+    where
+        T: Any + Clone
+    {
+        let handle = OwnedArgument::from_handle(SliceRef::new(item));
+        
+        Self
+        {
+            inner: InnerArgument::new_owned(handle)
+        }
+    }
+    
     /// Creates a borrowed reference to the source argument.
     #[inline(always)]
     pub fn as_ref(&'a self) -> Self
@@ -353,6 +575,24 @@ impl<'a> Argument<'a>
         }
     }
     
+    /// Consumes the argument, returning an argument that does not borrow anything.
+    ///
+    /// Borrowed contents get cloned, with borrowed slices being promoted into
+    /// String or `Vec<T>`. Owned contents are moved without being copied.
+    #[inline(always)]
+    pub fn into_static(self) -> Argument<'static>
+    {
+        let owned =
+        match self.inner_contents()
+        {
+            RawArgument::Borrowed(b) => b.clone_object(),
+            RawArgument::Owned(o) if o.raw_ref().is_borrowed_slice() => o.raw_ref().clone_object(),
+            RawArgument::Owned(o) => o
+        };
+        
+        Argument::from(owned)
+    }
+    
     /// Consumes the argument itself, returning what kind of argument it is.
     #[inline(always)]
     pub fn into_inner(self) -> ArgumentKind<'a>
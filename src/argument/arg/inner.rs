@@ -69,50 +69,74 @@ impl InnerArgument<'_>
     }
     
     
+    /// Checks if the contents are owned.
+    ///
+    /// Borrowed slices are stored as owned handles, but are not considered owned.
     #[inline(always)]
     pub fn is_owned(&self) -> bool
     {
-        matches!(self.discriminant(), Discriminant::Inlined | Discriminant::Allocated )
+        !self.is_borrowed()
     }
     
     
     #[inline(always)]
     pub fn is_borrowed(&self) -> bool
     {
-        matches!(self.discriminant(), Discriminant::Borrowed)
+        matches!(self.discriminant(), Discriminant::Borrowed) || self.is_borrowed_slice()
     }
     
     
+    /// Checks if the storage is an owned handle over a borrowed slice.
     #[inline(always)]
-    pub fn to_mut(&mut self) -> &mut dyn Any
+    pub fn is_borrowed_slice(&self) -> bool
     {
         match self.discriminant()
         {
-            Discriminant::Borrowed =>
+            Discriminant::Borrowed => false,
+            _ => unsafe { self.owned.raw_ref().is_borrowed_slice() }
+        }
+    }
+    
+    
+    /// Acquires the handle to the contents, whether they are owned or borrowed.
+    #[inline(always)]
+    pub fn handle(&self) -> &dyn VariantHandle
+    {
+        match self.discriminant()
+        {
+            Discriminant::Borrowed => unsafe { self.ref_ },
+            _ => unsafe { self.owned.raw_ref() }
+        }
+    }
+    
+    
+    #[inline(always)]
+    pub fn to_mut(&mut self) -> &mut dyn Any
+    {
+        if self.is_borrowed()
+        {
+            let owned : OwnedArgument = self.handle().clone_object();
+            
+            // Borrowed slices are owned handles, which have to be freed.
+            if self.is_borrowed_slice()
             {
-                let owned : OwnedArgument = 
                 unsafe
                 {
-                    self.ref_.clone_object()
-                };
-                
-                *self = InnerArgument::new_owned(owned);
-                
-                match self.discriminant()
-                {
-                    Discriminant::Inlined | Discriminant::Allocated =>
-                    unsafe
-                    {
-                        &mut *self.owned
-                    },
-                    _ => unreachable!()
+                    ManuallyDrop::drop(&mut self.owned);
                 }
             }
-            _ =>
+            
+            *self = InnerArgument::new_owned(owned);
+        }
+        
+        match self.discriminant()
+        {
+            Discriminant::Inlined | Discriminant::Allocated | Discriminant::Contiguous =>
             unsafe
             {
-                &mut *self.owned
-            }
+                &mut **self.owned
+            },
+            _ => unreachable!()
         }
     }
     
@@ -191,12 +215,24 @@ impl<'a> InnerArgument<'a>
     }
     
     
+    /// Consumes the storage, returning what kind of argument it is.
+    ///
+    /// Borrowed slices cannot be returned as `&dyn Any`, so they get promoted
+    /// into their owned counterparts instead.
     #[inline(always)]
     pub fn into_inner(self) -> ArgumentKind<'a>
     {
         match self.discriminant()
         {
             Discriminant::Borrowed => ArgumentKind::Borrowed(unsafe { self.ref_unchecked() }),
+            _ if self.is_borrowed_slice() =>
+            {
+                let promoted = self.handle().clone_object();
+                
+                drop(ManuallyDrop::into_inner(unsafe { self.owned }));
+                
+                ArgumentKind::Owned(promoted)
+            }
             _ => ArgumentKind::Owned(ManuallyDrop::into_inner(unsafe { self.owned }))
         }
     }
@@ -2,6 +2,7 @@
 use alloc::boxed::Box;
 
 use super::{
+    contiguous::Contiguous,
     discriminant::Discriminant,
     inlined::Inlined,
     VariantHandle
@@ -13,7 +14,9 @@ pub enum BoxedArgument
     /// The contents have been allocated.
     Allocated(Box<dyn VariantHandle>),
     /// The contents have been inlined.
-    Inlined(Inlined)
+    Inlined(Inlined),
+    /// The contents are stored inside of a block of contiguous values.
+    Contiguous(Contiguous)
 }
 
 impl BoxedArgument
@@ -48,6 +51,7 @@ impl BoxedArgument
 
                 Self::Allocated(allocated)
             }
+            Discriminant::Contiguous => Self::Contiguous(Contiguous::from(store)),
             _ => unreachable!()
         }
     }
@@ -0,0 +1,218 @@
+#[cfg(no_std)]
+use core::{
+    any::Any,
+    error,
+    fmt
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    error,
+    fmt
+};
+
+/// An error raised when an argument cannot be coerced into a type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoerceError
+{
+    /// There is no conversion from the argument's type into the target type.
+    Mismatch,
+    /// The conversion exists, but the value is outside of the target type's range.
+    Overflow
+}
+
+impl fmt::Display for CoerceError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Mismatch => f.write_str("no conversion into the target type"),
+            Self::Overflow => f.write_str("value is out of the target type's range")
+        }
+    }
+}
+
+impl error::Error for CoerceError {}
+
+/// How strictly an argument's type has to match its target type.
+///
+/// This is used by Signature and Arguments::extract, which opt into coercion through it.
+/// The default, Coercion::Exact, does not coerce.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Coercion
+{
+    /// Only the exact type matches.
+    #[default]
+    Exact,
+    /// The lossless numeric conversions of Argument::coerce are also accepted.
+    Lossless,
+    /// The numeric conversions of Argument::coerce_lossy are also accepted,
+    /// as long as the value fits into the target type.
+    Lossy
+}
+
+/// Performs a value-checked conversion between numeric primitives.
+trait LossyTarget : Sized
+{
+    fn from_lossy(src: &dyn Any) -> Result<Self, CoerceError>;
+}
+
+macro_rules! lossy_int
+{
+    ($($target:ty),*) =>
+    {
+        $(
+            impl LossyTarget for $target
+            {
+                fn from_lossy(src: &dyn Any) -> Result<Self, CoerceError>
+                {
+                    lossy_int!(@ints src, $target;
+                               i8, i16, i32, i64, i128, isize,
+                               u8, u16, u32, u64, u128, usize);
+                    
+                    // Floats are truncated, and must lie within the target's range.
+                    // Both bounds are powers of two, which are exact as floats.
+                    let min = <$target>::MIN as f64;
+                    let max = ((<$target>::MAX / 2 + 1) as f64) * 2.0;
+                    
+                    let float =
+                    if let Some(v) = src.downcast_ref::<f64>() { *v }
+                    else if let Some(v) = src.downcast_ref::<f32>() { *v as f64 }
+                    else { return Err(CoerceError::Mismatch) };
+                    
+                    if float >= min && float < max
+                    {
+                        Ok(float as $target)
+                    }
+                    else { Err(CoerceError::Overflow) }
+                }
+            }
+        )*
+    };
+    
+    (@ints $src:ident, $target:ty; $($from:ty),*) =>
+    {
+        $(
+            if let Some(v) = $src.downcast_ref::<$from>()
+            {
+                return <$target>::try_from(*v).map_err(|_| CoerceError::Overflow);
+            }
+        )*
+    };
+}
+
+macro_rules! lossy_float
+{
+    ($($target:ty),*) =>
+    {
+        $(
+            impl LossyTarget for $target
+            {
+                fn from_lossy(src: &dyn Any) -> Result<Self, CoerceError>
+                {
+                    lossy_float!(@from src, $target;
+                                 i8, i16, i32, i64, i128, isize,
+                                 u8, u16, u32, u64, u128, usize, f32, f64);
+                    
+                    Err(CoerceError::Mismatch)
+                }
+            }
+        )*
+    };
+    
+    (@from $src:ident, $target:ty; $($from:ty),*) =>
+    {
+        $(
+            if let Some(v) = $src.downcast_ref::<$from>()
+            {
+                let output = *v as $target;
+                
+                // Infinite sources stay infinite, while finite ones must not overflow.
+                #[allow(clippy::unnecessary_cast)]
+                let source_finite = (*v as f64).is_finite();
+                
+                return
+                if output.is_infinite() && source_finite
+                {
+                    Err(CoerceError::Overflow)
+                } else { Ok(output) };
+            }
+        )*
+    };
+}
+
+lossy_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+lossy_float!(f32, f64);
+
+/// Writes a converted value into out, which must be a `&mut Option<T>`.
+///
+/// Lossless conversions are the ones provided by `From` in the standard library.
+#[inline(always)]
+fn coerce_into(src: &dyn Any, out: &mut dyn Any, lossy: bool) -> Result<(), CoerceError>
+{
+    macro_rules! targets
+    {
+        ($($target:ty: [$($from:ty),*]);* $(;)?) =>
+        {
+            $(
+                if let Some(slot) = out.downcast_mut::<Option<$target>>()
+                {
+                    $(
+                        if let Some(v) = src.downcast_ref::<$from>()
+                        {
+                            *slot = Some(<$target>::from(*v));
+                            return Ok(());
+                        }
+                    )*
+                    
+                    if lossy
+                    {
+                        *slot = Some(<$target as LossyTarget>::from_lossy(src)?);
+                        return Ok(());
+                    }
+                    
+                    return Err(CoerceError::Mismatch);
+                }
+            )*
+        };
+    }
+    
+    targets!
+    {
+        i8: [];
+        i16: [i8, u8];
+        i32: [i8, i16, u8, u16];
+        i64: [i8, i16, i32, u8, u16, u32];
+        i128: [i8, i16, i32, i64, u8, u16, u32, u64];
+        isize: [i8, i16, u8];
+        u8: [];
+        u16: [u8];
+        u32: [u8, u16];
+        u64: [u8, u16, u32];
+        u128: [u8, u16, u32, u64];
+        usize: [u8, u16];
+        f32: [i8, i16, u8, u16];
+        f64: [i8, i16, i32, u8, u16, u32, f32];
+    }
+    
+    Err(CoerceError::Mismatch)
+}
+
+/// Converts src into T, assuming that src is not already of type T.
+///
+/// If lossy is false, only conversions which preserve every value are attempted.
+/// Otherwise, any numeric primitive converts into any other, and values outside
+/// of the target's range are reported as CoerceError::Overflow.
+#[inline(always)]
+pub(crate) fn coerce_numeric<T>(src: &dyn Any, lossy: bool) -> Result<T, CoerceError>
+where
+    T: Any
+{
+    let mut slot : Option<T> = None;
+    
+    coerce_into(src, &mut slot, lossy)?;
+    
+    slot.ok_or(CoerceError::Mismatch)
+}
@@ -0,0 +1,202 @@
+#[cfg(no_std)]
+use ::alloc::{alloc, vec::Vec};
+
+#[cfg(no_std)]
+use core::{
+    any::Any,
+    mem::ManuallyDrop,
+    ptr::NonNull
+};
+
+#[cfg(not(no_std))]
+use std::{
+    alloc,
+    any::Any,
+    mem::ManuallyDrop,
+    ptr::NonNull
+};
+
+use super::{owned::OwnedArgument, VariantHandle};
+
+/// The header in front of a block of contiguous values.
+struct BlockHeader
+{
+    /// The number of values that have yet to be freed.
+    live: usize,
+    /// The layout the block has been allocated with.
+    layout: alloc::Layout
+}
+
+/// A single value inside of a block.
+///
+/// Each value carries a pointer back to the header, so that it can be
+/// released on its own.
+#[repr(C)]
+struct Slot<T>
+{
+    header: *mut BlockHeader,
+    value: T
+}
+
+/// Moves a set of values into a single allocation, returning an owned argument per value.
+///
+/// The block is freed once every owned argument has been dropped or moved out of.
+#[inline(always)]
+pub(crate) fn from_values<T>(values: Vec<T>) -> Vec<OwnedArgument>
+where
+    T: Any + Clone
+{
+    if values.is_empty()
+    {
+        return Vec::new();
+    }
+    
+    let (layout, offset) =
+    alloc::Layout::new::<BlockHeader>()
+        .extend(alloc::Layout::array::<Slot<T>>(values.len()).unwrap())
+        .unwrap();
+    
+    let layout = layout.pad_to_align();
+    
+    let block = unsafe { alloc::alloc(layout) };
+    
+    if block.is_null()
+    {
+        alloc::handle_alloc_error(layout);
+    }
+    
+    let header = block.cast::<BlockHeader>();
+    
+    // Safety: The block has been allocated with room for the header and every slot.
+    unsafe
+    {
+        header.write(BlockHeader
+        {
+            live: values.len(),
+            layout
+        });
+    }
+    
+    let slots = unsafe { block.add(offset).cast::<Slot<T>>() };
+    
+    values.into_iter()
+          .enumerate()
+          .map(|(i, value)|
+          unsafe
+          {
+              let slot = slots.add(i);
+              
+              slot.write(Slot
+              {
+                  header,
+                  value
+              });
+              
+              let pointer : *mut dyn VariantHandle = &raw mut (*slot).value;
+              
+              OwnedArgument::from_contiguous(pointer)
+          })
+          .collect()
+}
+
+/// Releases a value's slot, freeing the block if it is the last one.
+///
+/// # Safety
+/// The pointer must point to a value created by from_values, which
+/// must not be released twice.
+#[inline(always)]
+unsafe fn release(pointer: *mut dyn VariantHandle, drop_value: bool)
+{
+    unsafe
+    {
+        // Slot is repr(C), so the value comes right after the header pointer,
+        // padded to the value's alignment.
+        let offset = size_of::<*mut BlockHeader>().next_multiple_of(align_of_val(&*pointer));
+        
+        let header = pointer.cast::<u8>()
+                            .sub(offset)
+                            .cast::<*mut BlockHeader>()
+                            .read();
+        
+        if drop_value
+        {
+            pointer.drop_in_place();
+        }
+        
+        (*header).live -= 1;
+        
+        if (*header).live == 0
+        {
+            let layout = (*header).layout;
+            
+            alloc::dealloc(header.cast::<u8>(), layout);
+        }
+    }
+}
+
+/// A value stored inside of a block.
+#[repr(transparent)]
+pub struct Contiguous
+{
+    contents: *mut dyn VariantHandle
+}
+
+impl Drop for Contiguous
+{
+    #[inline(always)]
+    fn drop(&mut self)
+    {
+        // Safety: We own the slot, and it gets released once.
+        unsafe
+        {
+            release(self.contents, true);
+        }
+    }
+}
+
+impl Contiguous
+{
+    #[inline(always)]
+    pub fn pointer(&self) -> NonNull<dyn VariantHandle>
+    {
+        debug_assert!(!self.contents.is_null());
+        
+        unsafe
+        {
+            NonNull::new_unchecked(self.contents)
+        }
+    }
+    
+    /// Moves the value out, releasing its slot.
+    ///
+    /// # Safety
+    /// This assumes that the value is of type T.
+    #[inline(always)]
+    pub unsafe fn into_value<T>(self) -> T
+    where
+        T: Any + Clone
+    {
+        let store = ManuallyDrop::new(self);
+        
+        unsafe
+        {
+            let output = store.contents.cast::<T>().read();
+            
+            release(store.contents, false);
+            
+            output
+        }
+    }
+}
+
+impl From<*mut dyn VariantHandle> for Contiguous
+{
+    #[inline(always)]
+    fn from(pointer: *mut dyn VariantHandle) -> Self
+    {
+        Self
+        {
+            contents: pointer
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::SliceRef;
+
+#[cfg(no_std)]
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::any::Any;
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    borrow::Cow,
+    rc::Rc,
+    sync::Arc
+};
+
+/// A type that can be read through the standard pointer wrappers.
+///
+/// This is implemented for every sized `T: Any + Clone`, which is read from `T`,
+/// `&'static T`, `Box<T>`, `Rc<T>`, `Arc<T>` and `Cow<'static, T>`.
+///
+/// In addition, `str` is read from String and the same wrappers over str, while `[T]`
+/// is read from `Vec<T>` and the same wrappers over `[T]`. Both are also read from
+/// borrowed slices, which are created by Argument::new_borrowed_str and
+/// Argument::new_borrowed_slice.
+pub trait DerefArgument
+{
+    /// Tries to read a value as Self, seeing through the supported wrappers.
+    fn deref_any(value: &dyn Any) -> Option<&Self>;
+}
+
+/// Tries each listed wrapper type in order, returning the dereferenced value.
+macro_rules! deref_through
+{
+    ($value:ident; $($wrapper:ty),*) =>
+    {
+        $(
+            if let Some(v) = $value.downcast_ref::<$wrapper>()
+            {
+                return Some(&**v);
+            }
+        )*
+    };
+}
+
+impl<T> DerefArgument for T
+where
+    T: Any + Clone
+{
+    #[inline(always)]
+    fn deref_any(value: &dyn Any) -> Option<&T>
+    {
+        if let Some(v) = value.downcast_ref::<T>()
+        {
+            return Some(v);
+        }
+        
+        deref_through!(value; &'static T, Box<T>, Rc<T>, Arc<T>, Cow<'static, T>);
+        
+        None
+    }
+}
+
+impl DerefArgument for str
+{
+    #[inline(always)]
+    fn deref_any(value: &dyn Any) -> Option<&str>
+    {
+        deref_through!(value; String, &'static str, Box<str>, Rc<str>, Arc<str>, Cow<'static, str>);
+        
+        // Borrowed slices never outlive the argument they are stored in.
+        if let Some(v) = value.downcast_ref::<SliceRef<str>>()
+        {
+            return Some(unsafe { v.get() });
+        }
+        
+        None
+    }
+}
+
+impl<T> DerefArgument for [T]
+where
+    T: Any + Clone
+{
+    #[inline(always)]
+    fn deref_any(value: &dyn Any) -> Option<&[T]>
+    {
+        deref_through!(value; Vec<T>, &'static [T], Box<[T]>, Rc<[T]>, Arc<[T]>, Cow<'static, [T]>);
+        
+        if let Some(v) = value.downcast_ref::<SliceRef<[T]>>()
+        {
+            return Some(unsafe { v.get() });
+        }
+        
+        None
+    }
+}
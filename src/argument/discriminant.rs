@@ -8,36 +8,41 @@ pub enum Discriminant
     Inlined,
     /// The storage pointer is owned and allocated.
     Allocated,
+    /// The storage pointer is owned and points into a block of contiguous values.
+    Contiguous,
     /// The storage pointer is borrowed.
     Borrowed
 }
 
 impl Discriminant
 {
-    /// Creates a discriminant from an inlined flag.
+    /// Creates a discriminant from the inlined and contiguous flags.
     ///
-    /// By definition, this should return either Inlined or Allocated.
+    /// By definition, this should not return Borrowed.
     #[inline(always)]
-    pub fn from_owned(inlined: bool) -> Self
+    pub fn from_owned(inlined: bool, contiguous: bool) -> Self
     {
         if inlined { Self::Inlined }
+        else if contiguous { Self::Contiguous }
         else { Self::Allocated }
     }
     
     /// Creates a new discriminant based around the following flags:
     ///
     /// owned: For determining if the pointer is owned or not,
-    /// inlined: For determining the pointer's inline status. This value
-    /// gets ignored if owned is false.
+    /// inlined: For determining the pointer's inline status,
+    /// contiguous: For determining if the pointer points into a block.
+    /// The last two values get ignored if owned is false.
     #[inline(always)]
     pub fn
-    from_info((inlined, owned): (bool, bool)) -> Self
+    from_info((inlined, contiguous, owned): (bool, bool, bool)) -> Self
     {
-        match (inlined, owned)
+        match (inlined, contiguous, owned)
         {
-            (_, false) => Self::Borrowed,
-            (true, true) => Self::Inlined,
-            (false, true) => Self::Allocated
+            (_, _, false) => Self::Borrowed,
+            (true, _, true) => Self::Inlined,
+            (false, true, true) => Self::Contiguous,
+            (false, false, true) => Self::Allocated
         }
     }
 }
@@ -2,14 +2,24 @@ mod variant_info;
 mod inlined;
 mod owned;
 mod boxed_argument;
+mod contiguous;
 mod discriminant;
+mod coerce;
+mod deref;
+mod slice_ref;
 // Unsure what to do about it.
 //mod borrowed_arg;
 
 pub use owned::OwnedArgument;
+pub use coerce::{Coercion, CoerceError};
+pub use deref::DerefArgument;
 
 pub(crate) use variant_info::VariantHandle;
+pub(crate) use slice_ref::SliceRef;
+pub(crate) use contiguous::from_values as contiguous_from_values;
 
 mod arg;
+mod switch;
 
 pub use arg::{Argument, ArgumentKind};
+pub use switch::ArgumentSwitch;
@@ -20,7 +20,10 @@ use std::{
     ops
 };
 
+use crate::{ConversionRegistry, Converted, DerefArgument};
+
 use super::{
+    coerce::{coerce_numeric, CoerceError},
     discriminant::Discriminant,
     boxed_argument::BoxedArgument,
     inlined::Inlined,
@@ -39,6 +42,7 @@ pub struct OwnedArgument
     /// cannot use this pointer directly.
     pointer: *mut dyn VariantHandle,
     inlined: bool,
+    contiguous: bool,
     owned: bool
 }
 
@@ -114,6 +118,17 @@ impl OwnedArgument
     pub fn new<T>(item: T) -> Self
     where
         T: Any + Clone
+    {
+        Self::from_handle(item)
+    }
+    
+    /// Creates a new OwnedArgument based around any variant handle.
+    ///
+    /// This follows the same storage rules as OwnedArgument::new.
+    #[inline(always)]
+    pub(crate) fn from_handle<T>(item: T) -> Self
+    where
+        T: VariantHandle
     {
         if size_of::<T>() <= size_of::<*const ()>()
         {
@@ -133,6 +148,7 @@ impl OwnedArgument
                     store.assume_init()
                 },
                 inlined: true,
+                contiguous: false,
                 owned: true
             }
         }
@@ -146,10 +162,27 @@ impl OwnedArgument
             {
                 pointer,
                 inlined: false,
+                contiguous: false,
                 owned: true
             }
         }
     }
+    
+    /// Creates a new OwnedArgument around a value inside of a block of contiguous values.
+    ///
+    /// # Safety
+    /// The pointer must come from a block, and must only be owned by this argument.
+    #[inline(always)]
+    pub(crate) unsafe fn from_contiguous(pointer: *mut dyn VariantHandle) -> Self
+    {
+        Self
+        {
+            pointer,
+            inlined: false,
+            contiguous: true,
+            owned: true
+        }
+    }
 
     #[inline(always)]
     unsafe fn pointer_metadata(&self) -> *mut dyn VariantHandle
@@ -164,7 +197,7 @@ impl OwnedArgument
         {
             Discriminant::Inlined =>
             unsafe { self.inner_inlined().pointer() },
-            Discriminant::Allocated =>
+            Discriminant::Allocated | Discriminant::Contiguous =>
             unsafe { NonNull::new_unchecked(self.pointer) },
             _ => unreachable!()
         }
@@ -176,14 +209,14 @@ impl OwnedArgument
     #[inline(always)]
     pub(crate) fn owned_discriminant(&self) -> Discriminant
     {
-        Discriminant::from_owned(self.inlined)
+        Discriminant::from_owned(self.inlined, self.contiguous)
     }
     
     /// Acquires the discriminant based around the OwnedPointer's storage information.
     #[inline(always)]
     pub(crate) fn discriminant(&self) -> Discriminant
     {
-        Discriminant::from_info((self.inlined, self.owned))
+        Discriminant::from_info((self.inlined, self.contiguous, self.owned))
     }
     
     /// Checks if the storage is inlined or not.
@@ -327,6 +360,18 @@ impl OwnedArgument
                     pointer.cast::<T>().read()
                 }
             }
+            BoxedArgument::Contiguous(c) =>
+            {
+                #[cfg(debug_assertions)]
+                {
+                    assert!(pointer_matches::<T>(c.pointer().as_ptr()));
+                }
+                
+                unsafe
+                {
+                    c.into_value()
+                }
+            }
         }
     }
     
@@ -350,6 +395,75 @@ impl OwnedArgument
         else { None }
     }
     
+    /// Coerces the owned argument into a cloned object of type T.
+    ///
+    /// An exact match is tried first. Otherwise, this falls back to the lossless
+    /// conversions between the integer and float primitives, such as i32 into i64
+    /// or f32 into f64.
+    ///
+    /// Returns None if neither succeeds.
+    #[inline(always)]
+    pub fn coerce<T>(&self) -> Option<T>
+    where
+        T: Any + Clone
+    {
+        match self.downcast_cloned::<T>()
+        {
+            Some(t) => Some(t),
+            None => coerce_numeric(&**self, false).ok()
+        }
+    }
+    
+    /// Coerces the owned argument into a cloned object of type T, allowing lossy conversions.
+    ///
+    /// Besides the conversions done by coerce, any integer or float primitive converts
+    /// into any other. Floats are truncated when converted into integers.
+    ///
+    /// # Return values
+    /// Ok(T): The owned argument is either T, or has been converted into T.
+    /// Err(CoerceError::Overflow): The value is outside of T's range, or is NaN.
+    /// Err(CoerceError::Mismatch): There is no conversion into T.
+    #[inline(always)]
+    pub fn coerce_lossy<T>(&self) -> Result<T, CoerceError>
+    where
+        T: Any + Clone
+    {
+        match self.downcast_cloned::<T>()
+        {
+            Some(t) => Ok(t),
+            None => coerce_numeric(&**self, true)
+        }
+    }
+    
+    /// Converts the owned argument into T through a conversion registry.
+    ///
+    /// An exact match is tried first, followed by the registered conversions, which
+    /// may be chained. The returned value lists the conversions that were applied.
+    ///
+    /// Returns None if there is no successful conversion.
+    #[inline(always)]
+    pub fn convert<T>(&self, registry: &ConversionRegistry) -> Option<Converted<T>>
+    where
+        T: Any + Clone
+    {
+        registry.convert(&**self)
+    }
+    
+    /// Downcasts a reference to the owned argument into T, seeing through pointer wrappers.
+    ///
+    /// Besides T itself, this reads T out of `&'static T`, `Box<T>`, `Rc<T>`,
+    /// `Arc<T>` and `Cow<'static, T>`. It also reads str out of String and
+    /// `[T]` out of `Vec<T>`. Refer to DerefArgument for the full list.
+    ///
+    /// Returns None if the owned argument is none of those.
+    #[inline(always)]
+    pub fn downcast_deref<T>(&self) -> Option<&T>
+    where
+        T: DerefArgument + ?Sized
+    {
+        T::deref_any(&**self)
+    }
+    
     /// Returns the cloned contents of the inner type of an OwnedArgument without performing any checks.
     ///
     /// # Safety
@@ -0,0 +1,100 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::any::{Any, TypeId};
+
+#[cfg(not(no_std))]
+use std::any::{Any, TypeId};
+
+use super::{OwnedArgument, VariantHandle};
+
+/// A handle over borrowed, unsized contents, such as str or `[T]`.
+///
+/// As `&str` cannot be turned into a trait object, the reference itself gets stored
+/// inside an owned argument instead. Said argument must never outlive the borrow,
+/// which is why this type does not implement Clone: cloning goes through
+/// clone_object, which promotes the contents into String or `Vec<T>`.
+pub(crate) struct SliceRef<T>
+where
+    T: ?Sized
+{
+    contents: *const T
+}
+
+impl<T> SliceRef<T>
+where
+    T: ?Sized
+{
+    /// Creates a new handle over a borrowed item.
+    ///
+    /// The handle is only valid for as long as item is.
+    #[inline(always)]
+    pub fn new(item: &T) -> Self
+    {
+        Self
+        {
+            contents: item
+        }
+    }
+    
+    /// Acquires the borrowed contents.
+    ///
+    /// # Safety
+    /// The borrow that created the handle must still be alive.
+    #[inline(always)]
+    pub unsafe fn get(&self) -> &T
+    {
+        unsafe
+        {
+            &*self.contents
+        }
+    }
+}
+
+impl VariantHandle for SliceRef<str>
+{
+    #[inline(always)]
+    fn clone_object(&self) -> OwnedArgument
+    {
+        OwnedArgument::new(String::from(unsafe { self.get() }))
+    }
+    
+    #[inline(always)]
+    fn clone_borrowed(&self) -> OwnedArgument
+    {
+        OwnedArgument::from_handle(Self { contents: self.contents })
+    }
+    
+    #[inline(always)]
+    fn promoted_type_id(&self) -> Option<TypeId>
+    {
+        Some(TypeId::of::<String>())
+    }
+}
+
+impl<T> VariantHandle for SliceRef<[T]>
+where
+    T: Any + Clone
+{
+    #[inline(always)]
+    fn clone_object(&self) -> OwnedArgument
+    {
+        OwnedArgument::new(Vec::from(unsafe { self.get() }))
+    }
+    
+    #[inline(always)]
+    fn clone_borrowed(&self) -> OwnedArgument
+    {
+        OwnedArgument::from_handle(Self { contents: self.contents })
+    }
+    
+    #[inline(always)]
+    fn promoted_type_id(&self) -> Option<TypeId>
+    {
+        Some(TypeId::of::<Vec<T>>())
+    }
+}
@@ -0,0 +1,206 @@
+#[cfg(no_std)]
+use core::any::{Any, TypeId};
+
+#[cfg(not(no_std))]
+use std::any::{Any, TypeId};
+
+use super::Argument;
+
+/// The backing trait for [match_arg].
+///
+/// This is implemented for `&Argument`, `&mut Argument` and `Argument`,
+/// which respectively hand out `&T`, `&mut T` and `T` to a matching arm.
+///
+/// [match_arg]: crate::match_arg
+#[doc(hidden)]
+pub trait ArgumentSwitch : Sized
+{
+    /// The value handed out to an arm matching type T.
+    type Output<T: Any + Clone>;
+    
+    /// Returns the TypeId of the argument's inner contents.
+    fn switch_type_id(&self) -> TypeId;
+    
+    /// Converts the argument into the arm's value.
+    ///
+    /// # Safety
+    /// The argument's inner contents must be of type T.
+    unsafe fn switch_unchecked<T>(self) -> Self::Output<T>
+    where
+        T: Any + Clone;
+}
+
+impl<'b> ArgumentSwitch for &'b Argument<'_>
+{
+    type Output<T: Any + Clone> = &'b T;
+    
+    #[inline(always)]
+    fn switch_type_id(&self) -> TypeId
+    {
+        (***self).type_id()
+    }
+    
+    #[inline(always)]
+    unsafe fn switch_unchecked<T>(self) -> &'b T
+    where
+        T: Any + Clone
+    {
+        unsafe
+        {
+            self.downcast_ref_unchecked()
+        }
+    }
+}
+
+impl<'b> ArgumentSwitch for &'b mut Argument<'_>
+{
+    type Output<T: Any + Clone> = &'b mut T;
+    
+    #[inline(always)]
+    fn switch_type_id(&self) -> TypeId
+    {
+        self.value_type_id()
+    }
+    
+    /// If the argument is borrowed, this clones the contents first.
+    #[inline(always)]
+    unsafe fn switch_unchecked<T>(self) -> &'b mut T
+    where
+        T: Any + Clone
+    {
+        let binding = self.to_mut();
+        
+        debug_assert!(binding.is::<T>());
+        
+        unsafe
+        {
+            &mut *(binding as *mut dyn Any as *mut T)
+        }
+    }
+}
+
+impl ArgumentSwitch for Argument<'_>
+{
+    type Output<T: Any + Clone> = T;
+    
+    #[inline(always)]
+    fn switch_type_id(&self) -> TypeId
+    {
+        self.value_type_id()
+    }
+    
+    /// If the argument is borrowed, this clones the contents instead.
+    #[inline(always)]
+    unsafe fn switch_unchecked<T>(self) -> T
+    where
+        T: Any + Clone
+    {
+        unsafe
+        {
+            if self.is_owned()
+            {
+                self.downcast_owned_unchecked()
+            }
+            else if self.handle().is_borrowed_slice()
+            {
+                // Borrowed slices report the TypeId of their promoted type.
+                self.downcast_cloned::<T>()
+                    .unwrap_unchecked()
+            }
+            else
+            {
+                self.downcast_cloned_unchecked()
+            }
+        }
+    }
+}
+
+/// Dispatches on the concrete type of an argument.
+///
+/// Each arm names a type followed by a closure-like binding, and the last
+/// arm must be a wildcard. The argument's TypeId is read once, then
+/// compared against each arm in order.
+///
+/// The binding depends on how the argument is passed:
+///  * `&Argument` binds `&T`.
+///  * `&mut Argument` binds `&mut T`. Borrowed arguments are cloned first, as with `to_mut`.
+///  * `Argument` binds `T`, using `downcast_owned`. Borrowed arguments are cloned instead.
+///
+/// The last two see borrowed str and `[T]` slices as String and `Vec<T>`, which
+/// they get promoted into. As `&Argument` cannot hand out a reference to those,
+/// such slices fall through to its wildcard, and are read with downcast_str
+/// or downcast_slice instead.
+///
+/// The wildcard arm is either `_ => expr`, or `_ => |arg| expr`
+/// in order to take back the unmatched argument.
+///
+/// ```
+/// use variadic_arguments::{match_arg, Argument};
+///
+/// let arg = Argument::new_owned(String::from("text"));
+///
+/// let len =
+/// match_arg!(&arg, {
+///     i32 => |x| *x as usize,
+///     String => |s| s.len(),
+///     _ => 0
+/// });
+///
+/// assert_eq!(len, 4);
+///
+/// let owned : String =
+/// match_arg!(arg, {
+///     String => |s| s,
+///     _ => |other| panic!("unexpected {other:?}")
+/// });
+///
+/// assert_eq!(owned, "text");
+/// ```
+#[macro_export]
+macro_rules! match_arg
+{
+    ($arg:expr, { $($arms:tt)+ }) =>
+    {
+        {
+            let arg = $arg;
+            let id = $crate::ArgumentSwitch::switch_type_id(&arg);
+            
+            $crate::match_arg!(@arms id, arg; $($arms)+)
+        }
+    };
+    
+    (@arms $id:ident, $arg:ident; _ => |$rest:pat_param| $default:expr $(,)?) =>
+    {
+        {
+            let $rest = $arg;
+            $default
+        }
+    };
+    
+    (@arms $id:ident, $arg:ident; _ => $default:expr $(,)?) =>
+    {
+        {
+            let _ = $arg;
+            $default
+        }
+    };
+    
+    (@arms $id:ident, $arg:ident; $ty:ty => |$bind:pat_param| $body:expr, $($tail:tt)+) =>
+    {
+        if $id == ::core::any::TypeId::of::<$ty>()
+        {
+            // Safety: The TypeId has been checked above.
+            let $bind =
+            unsafe
+            {
+                $crate::ArgumentSwitch::switch_unchecked::<$ty>($arg)
+            };
+            
+            $body
+        }
+        else
+        {
+            $crate::match_arg!(@arms $id, $arg; $($tail)+)
+        }
+    };
+}
@@ -1,8 +1,8 @@
 #[cfg(no_std)]
-use core::any::Any;
+use core::any::{Any, TypeId};
 
 #[cfg(not(no_std))]
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 use super::owned::OwnedArgument;
 
@@ -21,6 +21,48 @@ pub(crate) trait VariantHandle : Any
     /// it instead returns a creates an OwnedArgument from the cloned object. All of
     /// this workload is meant to maintain dyn compatibility.
     fn clone_object(&self) -> OwnedArgument;
+    
+    /// A wrapper for clone that keeps borrowed contents borrowed.
+    ///
+    /// This only differs from clone_object for handles over borrowed, unsized
+    /// contents, whose clone_object promotes the contents into an owned type.
+    #[inline(always)]
+    fn clone_borrowed(&self) -> OwnedArgument
+    {
+        self.clone_object()
+    }
+    
+    /// Returns the TypeId of the type clone_object promotes the contents into.
+    ///
+    /// This is only Some for handles over borrowed, unsized contents, such as str
+    /// or `[T]`, which are promoted into String or `Vec<T>`. These handles are stored
+    /// as owned arguments, but must not outlive the Argument holding them.
+    #[inline(always)]
+    fn promoted_type_id(&self) -> Option<TypeId>
+    {
+        None
+    }
+}
+
+impl dyn VariantHandle + '_
+{
+    /// Checks if the handle refers to borrowed, unsized contents.
+    #[inline(always)]
+    pub fn is_borrowed_slice(&self) -> bool
+    {
+        self.promoted_type_id()
+            .is_some()
+    }
+    
+    /// Returns the TypeId of the value, seeing borrowed slices as their promoted type.
+    #[inline(always)]
+    pub fn value_type_id(&self) -> TypeId
+    {
+        let any : &dyn Any = self;
+        
+        self.promoted_type_id()
+            .unwrap_or_else(|| any.type_id())
+    }
 }
 
 impl<T> VariantHandle for T
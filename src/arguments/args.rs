@@ -7,31 +7,78 @@ use alloc::{
 
 #[cfg(no_std)]
 use core::{
-    ops::{Deref, DerefMut},
+    any::{Any, TypeId},
+    cell::Cell,
+    fmt,
+    ops::{Deref, DerefMut, RangeBounds},
     slice::{Iter, IterMut}
 };
 
 #[cfg(not(no_std))]
 use std::{
-    ops::{Deref, DerefMut},
+    any::{Any, TypeId},
+    cell::Cell,
+    fmt,
+    ops::{Deref, DerefMut, RangeBounds},
     slice::{Iter, IterMut},
     vec::IntoIter as VecIntoIter
 };
 
-use super::MAX_ARG_COUNT;
+use super::{
+    typed,
+    ArgumentsBuilder,
+    ArgumentsRef,
+    CapacityError,
+    FromArguments,
+    JoinError,
+    MAX_ARG_COUNT
+};
+
+use crate::{argument::contiguous_from_values, Argument, Coercion};
 
-use crate::Argument;
+/// The cached result of checking whether every argument shares one type.
+#[derive(Clone, Copy)]
+enum Homogeneity
+{
+    /// The arguments have been accessed mutably since the last check.
+    Unknown,
+    /// The arguments have different types.
+    Mixed,
+    /// Every argument has the same type. Empty sets have no type.
+    ///
+    /// Borrowed slices count as their promoted type, which they
+    /// cannot be referenced as.
+    Uniform
+    {
+        type_id: Option<TypeId>,
+        has_slices: bool
+    }
+}
 
 /// A container for storing a set of arguments.
 ///
 /// While the inner storage's size is fixed, the storage
 /// can be accessed mutably.
-#[repr(transparent)]
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Arguments<'a>
 {
     /// The inner table for storing a slice of arguments.
-    table: Box<[Argument<'a>]>
+    table: Box<[Argument<'a>]>,
+    /// Whether or not the arguments share one type. This is reset
+    /// whenever the table is accessed mutably.
+    homogeneity: Cell<Homogeneity>
+}
+
+
+impl fmt::Debug for Arguments<'_>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Arguments")
+         .field("table", &self.table)
+         .finish()
+    }
 }
 
 
@@ -52,13 +99,75 @@ impl<'a> DerefMut for Arguments<'a>
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut [Argument<'a>]
     {
-        &mut self.table
+        self.table_mut()
     }
 }
 
 
 impl<'a> Arguments<'a>
 {
+    /// Creates a new instance around a table, which must not exceed MAX_ARG_COUNT.
+    #[inline(always)]
+    fn from_table(table: Box<[Argument<'a>]>) -> Self
+    {
+        debug_assert!(table.len() <= MAX_ARG_COUNT);
+        
+        Self
+        {
+            table,
+            homogeneity: Cell::new(Homogeneity::Unknown)
+        }
+    }
+    
+    
+    /// Acquires mutable access to the table, resetting the cached homogeneity.
+    #[inline(always)]
+    fn table_mut(&mut self) -> &mut [Argument<'a>]
+    {
+        self.homogeneity.set(Homogeneity::Unknown);
+        
+        &mut self.table
+    }
+    
+    
+    /// Imports a set of values that share one type.
+    ///
+    /// The values are moved into a single allocation, and each one becomes an owned
+    /// argument pointing into it. The allocation is freed once every argument has been
+    /// dropped or moved out. As every value has the same type, the result is already
+    /// known to be homogeneous.
+    ///
+    /// # Return values
+    /// Ok(Self): Value count is no more than MAX_ARG_COUNT.
+    /// Err(values): Value count is greater than MAX_ARG_COUNT.
+    #[inline(always)]
+    pub fn from_homogeneous<T>(values: Vec<T>) -> Result<Self, Vec<T>>
+    where
+        T: Any + Clone
+    {
+        if values.len() > MAX_ARG_COUNT
+        {
+            return Err(values);
+        }
+        
+        let output =
+        Self::from_table(contiguous_from_values(values).into_iter()
+                                                       .map(Argument::from)
+                                                       .collect());
+        
+        if !output.is_empty()
+        {
+            output.homogeneity.set(Homogeneity::Uniform
+            {
+                type_id: Some(TypeId::of::<T>()),
+                has_slices: false
+            });
+        }
+        
+        Ok(output)
+    }
+    
+    
     /// Imports a set of arguments from a boxed slice.
     ///
     /// # Return values
@@ -69,13 +178,7 @@ impl<'a> Arguments<'a>
     {
         if args.len() <= MAX_ARG_COUNT
         {
-            Ok
-            (
-                Self
-                {
-                    table: args
-                }
-            )
+            Ok(Self::from_table(args))
         }
         else { Err(args) }
     }
@@ -90,13 +193,7 @@ impl<'a> Arguments<'a>
     {
         if args.len() <= MAX_ARG_COUNT
         {
-            Ok
-            (
-                Self
-                {
-                    table: args.into_boxed_slice()
-                }
-            )
+            Ok(Self::from_table(args.into_boxed_slice()))
         }
         else { Err(args) }
     }
@@ -118,11 +215,7 @@ impl<'a> Arguments<'a>
     {
         if args.len() <= MAX_ARG_COUNT
         {
-            Ok(Self
-            {
-                table: args.collect()
-            }
-            )
+            Ok(Self::from_table(args.collect()))
         }
         else
         {
@@ -131,6 +224,294 @@ impl<'a> Arguments<'a>
     }
     
     
+    /// Joins two sets of arguments, keeping the order and state of each argument.
+    ///
+    /// # Return values
+    /// Ok(Self): The joined arguments.
+    /// Err(e): The joined length would exceed MAX_ARG_COUNT. Both sets are
+    /// handed back through the error, and its index is counted across second.
+    #[inline(always)]
+    pub fn concat(first: Self, second: Self) -> Result<Self, JoinError<'a>>
+    {
+        let total = first.len() + second.len();
+        
+        if total > MAX_ARG_COUNT
+        {
+            let error =
+            CapacityError
+            {
+                index: MAX_ARG_COUNT - first.len(),
+                rejected: total - MAX_ARG_COUNT
+            };
+            
+            return Err(JoinError { error, first, second });
+        }
+        
+        let mut table = first.table.into_vec();
+        
+        table.extend(second.table.into_vec());
+        
+        Ok(Self::from_table(table.into_boxed_slice()))
+    }
+    
+    
+    /// Adds other after the current arguments.
+    ///
+    /// Refer to Arguments::concat for the return values. The error's index
+    /// is counted across other.
+    #[inline(always)]
+    pub fn append(self, other: Self) -> Result<Self, JoinError<'a>>
+    {
+        Self::concat(self, other)
+    }
+    
+    
+    /// Adds other in front of the current arguments.
+    ///
+    /// This is Arguments::concat with other coming first, so refer to it for the
+    /// return values. As such, the error's index is counted across self.
+    #[inline(always)]
+    pub fn prepend(self, other: Self) -> Result<Self, JoinError<'a>>
+    {
+        Self::concat(other, self)
+    }
+    
+    
+    /// Splits the arguments into two sets at an index, without cloning them.
+    ///
+    /// # Return values
+    /// Ok((head, tail)): head contains the arguments before idx, and tail the rest.
+    /// Err(self): idx is greater than the length.
+    #[inline(always)]
+    pub fn split_at(self, idx: usize) -> Result<(Self, Self), Self>
+    {
+        if idx > self.len()
+        {
+            return Err(self);
+        }
+        
+        let mut head = self.table.into_vec();
+        
+        let tail = head.split_off(idx);
+        
+        Ok((Self::from_table(head.into_boxed_slice()), Self::from_table(tail.into_boxed_slice())))
+    }
+    
+    
+    /// Splits the first argument from the rest.
+    ///
+    /// Returns None if there are no arguments.
+    #[inline(always)]
+    pub fn split_first(self) -> Option<(Argument<'a>, Self)>
+    {
+        let mut table = self.table.into_vec();
+        
+        if table.is_empty()
+        {
+            return None;
+        }
+        
+        let first = table.remove(0);
+        
+        Some((first, Self::from_table(table.into_boxed_slice())))
+    }
+    
+    
+    /// Splits the last argument from the rest.
+    ///
+    /// Returns None if there are no arguments.
+    #[inline(always)]
+    pub fn split_last(self) -> Option<(Argument<'a>, Self)>
+    {
+        let mut table = self.table.into_vec();
+        
+        let last = table.pop()?;
+        
+        Some((last, Self::from_table(table.into_boxed_slice())))
+    }
+    
+    
+    /// Consumes the arguments, returning a builder for editing them.
+    ///
+    /// The inner table is reused, so this does not reallocate.
+    #[inline(always)]
+    pub fn into_builder(self) -> ArgumentsBuilder<'a>
+    {
+        ArgumentsBuilder::from_table(self.table.into_vec())
+    }
+    
+    
+    /// Consumes the arguments, returning a set that does not borrow anything.
+    ///
+    /// Each borrowed argument gets cloned, while owned arguments are moved
+    /// without being copied. Refer to Argument::into_static for more information.
+    #[inline(always)]
+    pub fn into_owned(self) -> Arguments<'static>
+    {
+        let table : Vec<Argument<'static>> =
+        self.table
+            .into_vec()
+            .into_iter()
+            .map(Argument::into_static)
+            .collect();
+        
+        Arguments::from_table(table.into_boxed_slice())
+    }
+    
+    
+    /// Creates a new set of arguments, borrowing each argument from self.
+    ///
+    /// Unlike clone, this does not copy any owned contents. Refer to Argument::as_ref
+    /// for more information.
+    #[inline(always)]
+    pub fn borrow_all(&self) -> Arguments<'_>
+    {
+        Arguments::from_table(self.table
+                                  .iter()
+                                  .map(Argument::as_ref)
+                                  .collect())
+    }
+    
+    
+    /// Creates a borrowed view over the arguments, without allocating.
+    #[inline(always)]
+    pub fn as_view(&self) -> ArgumentsRef<'_>
+    {
+        ArgumentsRef::from(self)
+    }
+    
+    
+    /// Creates a borrowed view over a range of arguments, without allocating.
+    ///
+    /// Returns None if the range is out of bounds.
+    #[inline(always)]
+    pub fn view<R>(&self, range: R) -> Option<ArgumentsRef<'_>>
+    where
+        R: RangeBounds<usize>
+    {
+        self.as_view()
+            .view(range)
+    }
+    
+    
+    /// Checks whether or not every argument shares one type.
+    ///
+    /// The result is cached until the arguments are accessed mutably.
+    #[inline(always)]
+    fn homogeneity(&self) -> Homogeneity
+    {
+        if let Homogeneity::Unknown = self.homogeneity.get()
+        {
+            let mut types = self.table.iter().map(|a| a.value_type_id());
+            
+            let computed =
+            match types.next()
+            {
+                Some(first) if types.all(|t| t == first) =>
+                Homogeneity::Uniform
+                {
+                    type_id: Some(first),
+                    has_slices: self.table.iter().any(|a| (**a).type_id() != first)
+                },
+                Some(_) => Homogeneity::Mixed,
+                None => Homogeneity::Uniform { type_id: None, has_slices: false }
+            };
+            
+            self.homogeneity.set(computed);
+        }
+        
+        self.homogeneity.get()
+    }
+    
+    
+    /// Checks whether or not every argument shares one type.
+    ///
+    /// Borrowed str and `[T]` slices count as String and `Vec<T>`.
+    /// Empty sets are considered homogeneous.
+    #[inline(always)]
+    pub fn is_homogeneous(&self) -> bool
+    {
+        matches!(self.homogeneity(), Homogeneity::Uniform { .. })
+    }
+    
+    
+    /// Returns the type shared by every argument.
+    ///
+    /// Returns None if the types differ, or if there are no arguments.
+    #[inline(always)]
+    pub fn homogeneous_type(&self) -> Option<TypeId>
+    {
+        match self.homogeneity()
+        {
+            Homogeneity::Uniform { type_id, .. } => type_id,
+            _ => None
+        }
+    }
+    
+    
+    /// Returns a reference to each argument, if every argument is of type T.
+    ///
+    /// The type check is cached, so repeated calls only check the types once.
+    /// Borrowed str and `[T]` slices cannot be read as String or `Vec<T>` by
+    /// reference, so any of those returns None.
+    #[inline(always)]
+    pub fn as_homogeneous<T>(&self) -> Option<Vec<&T>>
+    where
+        T: Any + Clone
+    {
+        match self.homogeneity()
+        {
+            Homogeneity::Uniform { type_id: Some(t), has_slices: false } if t == TypeId::of::<T>() => (),
+            Homogeneity::Uniform { type_id: None, .. } => (),
+            _ => return None
+        }
+        
+        // Safety: Every argument has been checked to be of type T.
+        let output =
+        self.table
+            .iter()
+            .map(|a| unsafe { a.downcast_ref_unchecked::<T>() })
+            .collect();
+        
+        Some(output)
+    }
+    
+    
+    /// Consumes the arguments, returning their values if every argument is of type T.
+    ///
+    /// # Return values
+    /// Ok(values): Owned arguments are moved out, while borrowed ones are cloned.
+    /// Borrowed slices are promoted into String or `Vec<T>`.
+    /// Err(self): At least one argument is not of type T.
+    #[inline(always)]
+    pub fn into_homogeneous<T>(self) -> Result<Vec<T>, Self>
+    where
+        T: Any + Clone
+    {
+        match self.homogeneity()
+        {
+            Homogeneity::Uniform { type_id: Some(t), .. } if t == TypeId::of::<T>() => (),
+            Homogeneity::Uniform { type_id: None, .. } => (),
+            _ => return Err(self)
+        }
+        
+        // Safety: Every argument has been checked to be of type T.
+        let output =
+        self.table
+            .into_vec()
+            .into_iter()
+            .map(|a|
+            unsafe
+            {
+                if a.is_owned() { a.downcast_owned_unchecked() }
+                else { a.downcast_cloned().unwrap_unchecked() }
+            })
+            .collect();
+        
+        Ok(output)
+    }
+    
+    
     /// Iterates over a borrowed set of arguments.
     #[inline(always)]
     pub fn iter(&self) -> Iter<'_, Argument<'a>>
@@ -143,7 +524,90 @@ impl<'a> Arguments<'a>
     #[inline(always)]
     pub fn iter_mut(&mut self) -> IterMut<'_, Argument<'a>>
     {
-        self.table.iter_mut()
+        self.table_mut().iter_mut()
+    }
+    
+    /// Iterates over references to each argument of type T, skipping the others.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, even when T is String or `Vec<T>`.
+    /// Use iter_of_mut or into_iter_of, which promote them, to include those.
+    #[inline(always)]
+    pub fn iter_of<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: Any + Clone
+    {
+        typed::iter_of(&self.table)
+    }
+    
+    /// Iterates over mutable references to each argument of type T, skipping the others.
+    ///
+    /// Borrowed arguments of type T become owned, as with Argument::to_mut.
+    #[inline(always)]
+    pub fn iter_of_mut<T>(&mut self) -> impl Iterator<Item = &mut T>
+    where
+        T: Any + Clone
+    {
+        typed::iter_of_mut(self.table_mut())
+    }
+    
+    /// Consumes the arguments, moving out each argument of type T.
+    ///
+    /// Owned arguments are moved through downcast_owned, while borrowed ones get cloned.
+    #[inline(always)]
+    pub fn into_iter_of<T>(self) -> impl Iterator<Item = T>
+    where
+        T: Any + Clone
+    {
+        typed::into_iter_of(self.table.into_iter())
+    }
+    
+    /// Returns a reference to the first argument of type T.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of.
+    #[inline(always)]
+    pub fn find<T>(&self) -> Option<&T>
+    where
+        T: Any + Clone
+    {
+        self.iter_of::<T>().next()
+    }
+    
+    /// Returns the index of the first argument of type T, which is the one find returns.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of. Use
+    /// Argument::value_type_id to match them by the type they promote into.
+    #[inline(always)]
+    pub fn position_of<T>(&self) -> Option<usize>
+    where
+        T: Any + Clone
+    {
+        typed::position_of::<T>(&self.table)
+    }
+    
+    /// Counts the arguments of type T, which are the ones iter_of yields.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of.
+    #[inline(always)]
+    pub fn count_of<T>(&self) -> usize
+    where
+        T: Any + Clone
+    {
+        typed::count_of::<T>(&self.table)
+    }
+    
+    /// Extracts the arguments as a tuple, such as `(i64, String)`.
+    ///
+    /// Each argument is cloned into its element's type. Coercion decides whether
+    /// an argument of another numeric type is converted, as in Argument::coerce_with.
+    ///
+    /// Returns None if the argument count differs from the tuple's length, or
+    /// if an argument does not convert into its element's type.
+    #[inline(always)]
+    pub fn extract<T>(&self, coercion: Coercion) -> Option<T>
+    where
+        T: FromArguments
+    {
+        T::from_arguments(&self.table, coercion)
     }
 }
 
@@ -0,0 +1,121 @@
+#[cfg(no_std)]
+use core::{
+    ops::{Bound, Deref, RangeBounds},
+    slice::Iter
+};
+
+#[cfg(not(no_std))]
+use std::{
+    ops::{Bound, Deref, RangeBounds},
+    slice::Iter
+};
+
+use super::Arguments;
+
+use crate::Argument;
+
+/// A borrowed view over a set of arguments.
+///
+/// Creating a view, or narrowing it down through [ArgumentsRef::view],
+/// neither allocates nor clones anything. This makes it suitable for
+/// passing the rest of a variadic list into a nested call.
+///
+/// [ArgumentsRef::view]: ArgumentsRef::view
+#[derive(Clone, Copy, Debug)]
+pub struct ArgumentsRef<'a>
+{
+    /// The viewed arguments.
+    table: &'a [Argument<'a>]
+}
+
+impl<'a> Deref for ArgumentsRef<'a>
+{
+    type Target = [Argument<'a>];
+    
+    #[inline(always)]
+    fn deref(&self) -> &[Argument<'a>]
+    {
+        self.table
+    }
+}
+
+impl<'a> From<&'a Arguments<'a>> for ArgumentsRef<'a>
+{
+    #[inline(always)]
+    fn from(args: &'a Arguments<'a>) -> Self
+    {
+        Self
+        {
+            table: args
+        }
+    }
+}
+
+/// Converts a range into a pair of bounds, which slices can be indexed with.
+#[inline(always)]
+fn bounds<R>(range: R) -> (Bound<usize>, Bound<usize>)
+where
+    R: RangeBounds<usize>
+{
+    (range.start_bound().cloned(), range.end_bound().cloned())
+}
+
+impl<'a> ArgumentsRef<'a>
+{
+    /// Narrows the view down to a range of arguments.
+    ///
+    /// Returns None if the range is out of bounds.
+    #[inline(always)]
+    pub fn view<R>(&self, range: R) -> Option<ArgumentsRef<'a>>
+    where
+        R: RangeBounds<usize>
+    {
+        self.table
+            .get(bounds(range))
+            .map(|table| Self { table })
+    }
+    
+    /// Splits the first argument from the rest of the view.
+    ///
+    /// Returns None if the view is empty.
+    #[inline(always)]
+    pub fn split_first(&self) -> Option<(&'a Argument<'a>, ArgumentsRef<'a>)>
+    {
+        self.table
+            .split_first()
+            .map(|(first, table)| (first, Self { table }))
+    }
+    
+    /// Iterates over the viewed arguments.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'a, Argument<'a>>
+    {
+        self.table.iter()
+    }
+    
+    /// Creates an Arguments instance borrowing each viewed argument.
+    ///
+    /// Unlike the view itself, this allocates a new table.
+    #[inline(always)]
+    pub fn to_arguments(&self) -> Arguments<'a>
+    {
+        match Arguments::create_from_iter(self.table.iter().map(Argument::as_ref))
+        {
+            Ok(a) => a,
+            // Views are created from Arguments, whose length is restricted.
+            Err(_) => unreachable!()
+        }
+    }
+}
+
+impl<'a> IntoIterator for ArgumentsRef<'a>
+{
+    type Item = &'a Argument<'a>;
+    type IntoIter = Iter<'a, Argument<'a>>;
+    
+    #[inline(always)]
+    fn into_iter(self) -> Iter<'a, Argument<'a>>
+    {
+        self.table.iter()
+    }
+}
@@ -1,10 +1,11 @@
 #[cfg(no_std)]
-use alloc::vec::Vec;
+use alloc::vec::{Drain, Vec};
 
 #[cfg(no_std)]
 use core::{
     any::Any,
-    ops::Deref
+    mem,
+    ops::{Bound, Deref, DerefMut, RangeBounds}
 };
 
 use crate::Argument;
@@ -12,10 +13,12 @@ use crate::Argument;
 #[cfg(not(no_std))]
 use std::{
     any::Any,
-    ops::Deref
+    mem,
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    vec::Drain
 };
 
-use super::{Arguments, MAX_ARG_COUNT};
+use super::{typed, Arguments, CapacityError, MAX_ARG_COUNT};
 
 /// A structure for creating Arguments.
 ///
@@ -25,7 +28,11 @@ use super::{Arguments, MAX_ARG_COUNT};
 pub struct ArgumentsBuilder<'a>
 {
     /// The inner contents for storing arguments.
-    table: Vec<Argument<'a>>
+    table: Vec<Argument<'a>>,
+    /// The amount of arguments added through the chaining methods.
+    chained: usize,
+    /// The first chained insertion that did not fit, along with the rejected count.
+    overflow: Option<CapacityError>
 }
 
 impl<'a> Deref for ArgumentsBuilder<'a>
@@ -39,6 +46,15 @@ impl<'a> Deref for ArgumentsBuilder<'a>
     }
 }
 
+impl<'a> DerefMut for ArgumentsBuilder<'a>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [Argument<'a>]
+    {
+        &mut self.table
+    }
+}
+
 impl ArgumentsBuilder<'_>
 {
     /// Creates a new instance of ArgumentsBuilder.
@@ -47,7 +63,9 @@ impl ArgumentsBuilder<'_>
     {
         Self
         {
-            table: Vec::new()
+            table: Vec::new(),
+            chained: 0,
+            overflow: None
         }
     }
     
@@ -62,7 +80,9 @@ impl ArgumentsBuilder<'_>
         
         Self
         {
-            table: Vec::with_capacity(cap)
+            table: Vec::with_capacity(cap),
+            chained: 0,
+            overflow: None
         }
     }
     
@@ -138,6 +158,22 @@ impl ArgumentsBuilder<'_>
 
 impl<'a> ArgumentsBuilder<'a>
 {
+    /// Creates a builder around an existing table.
+    ///
+    /// The table must not contain more than MAX_ARG_COUNT arguments.
+    #[inline(always)]
+    pub(super) fn from_table(table: Vec<Argument<'a>>) -> Self
+    {
+        debug_assert!(table.len() <= MAX_ARG_COUNT);
+        
+        Self
+        {
+            table,
+            chained: 0,
+            overflow: None
+        }
+    }
+    
     /// Removes an argument at the specified index.
     ///
     /// # Return values
@@ -162,6 +198,107 @@ impl<'a> ArgumentsBuilder<'a>
         self.table.pop()
     }
     
+    /// Tries to insert an argument at the specified index, shifting the following arguments.
+    ///
+    /// # Return values
+    /// Ok(()): Able to insert the argument itself.
+    /// Err(arg): Either the builder is already full, or idx is greater than the length.
+    #[inline(always)]
+    pub fn insert_at(&mut self, idx: usize, arg: Argument<'a>) -> Result<(), Argument<'a>>
+    {
+        if self.can_insert_args() && idx <= self.len()
+        {
+            self.table.insert(idx, arg);
+            Ok(())
+        } else { Err(arg) }
+    }
+    
+    /// Replaces the argument at the specified index.
+    ///
+    /// # Return values
+    /// Ok(previous): The argument that was at idx.
+    /// Err(arg): There are no arguments at idx.
+    #[inline(always)]
+    pub fn replace(&mut self, idx: usize, arg: Argument<'a>) -> Result<Argument<'a>, Argument<'a>>
+    {
+        match self.table.get_mut(idx)
+        {
+            Some(current) => Ok(mem::replace(current, arg)),
+            None => Err(arg)
+        }
+    }
+    
+    /// Swaps two arguments.
+    ///
+    /// Returns false, without swapping anything, if either index is out of bounds.
+    #[inline(always)]
+    pub fn swap(&mut self, a: usize, b: usize) -> bool
+    {
+        if a < self.len() && b < self.len()
+        {
+            self.table.swap(a, b);
+            true
+        } else { false }
+    }
+    
+    /// Shortens the builder, keeping the first len arguments.
+    ///
+    /// This does nothing if len is greater than the current length.
+    #[inline(always)]
+    pub fn truncate(&mut self, len: usize)
+    {
+        self.table.truncate(len);
+    }
+    
+    /// Removes every argument, keeping the allocated capacity.
+    #[inline(always)]
+    pub fn clear(&mut self)
+    {
+        self.table.clear();
+    }
+    
+    /// Keeps only the arguments matching pred, preserving their order.
+    #[inline(always)]
+    pub fn retain<F>(&mut self, pred: F)
+    where
+        F: FnMut(&Argument<'a>) -> bool
+    {
+        self.table.retain(pred);
+    }
+    
+    /// Removes a range of arguments, returning them as an iterator.
+    ///
+    /// Refer to Vec::drain for the behavior of the returned iterator.
+    ///
+    /// Returns None if the range is out of bounds.
+    #[inline(always)]
+    pub fn drain<R>(&mut self, range: R) -> Option<Drain<'_, Argument<'a>>>
+    where
+        R: RangeBounds<usize>
+    {
+        let start =
+        match range.start_bound()
+        {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.checked_add(1)?,
+            Bound::Unbounded => 0
+        };
+        
+        let end =
+        match range.end_bound()
+        {
+            Bound::Included(&e) => e.checked_add(1)?,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.len()
+        };
+        
+        // This check is done to prevent a panic.
+        if start <= end && end <= self.len()
+        {
+            Some(self.table.drain(start..end))
+        } else { None }
+    }
+    
     /// Tries to insert a generic, borrowed item.
     ///
     /// # Return values
@@ -179,6 +316,38 @@ impl<'a> ArgumentsBuilder<'a>
         } else { false }
     }
     
+    /// Tries to insert a borrowed string slice, without copying it.
+    ///
+    /// # Return values
+    /// true: We are able to insert the slice itself.
+    /// false: The table is already full.
+    #[inline(always)]
+    pub fn insert_borrowed_str(&mut self, borrowed: &'a str) -> bool
+    {
+        if self.can_insert_args()
+        {
+            self.table.push(Argument::new_borrowed_str(borrowed));
+            true
+        } else { false }
+    }
+    
+    /// Tries to insert a borrowed slice, without copying it.
+    ///
+    /// # Return values
+    /// true: We are able to insert the slice itself.
+    /// false: The table is already full.
+    #[inline(always)]
+    pub fn insert_borrowed_slice<T>(&mut self, borrowed: &'a [T]) -> bool
+    where
+        T: Any + Clone
+    {
+        if self.can_insert_args()
+        {
+            self.table.push(Argument::new_borrowed_slice(borrowed));
+            true
+        } else { false }
+    }
+    
     /// Tries to insert an argument that is already in a Argument format.
     ///
     /// # Return values
@@ -222,7 +391,198 @@ impl<'a> ArgumentsBuilder<'a>
         args.collect()
     }
     
+    /// Clones the contents of each borrowed argument matching pred.
+    ///
+    /// Owned arguments are left untouched, and pred is not called for them.
+    /// Returns the amount of arguments that became owned.
+    #[inline(always)]
+    pub fn make_owned_where<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&Argument<'a>) -> bool
+    {
+        let mut count = 0;
+        
+        for arg in self.table.iter_mut()
+        {
+            if arg.is_borrowed() && pred(arg)
+            {
+                *arg = arg.to_owned();
+                count += 1;
+            }
+        }
+        
+        count
+    }
+    
+    /// Iterates over references to each argument of type T, skipping the others.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, even when T is String or `Vec<T>`.
+    /// Use iter_of_mut or into_iter_of, which promote them, to include those.
+    #[inline(always)]
+    pub fn iter_of<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: Any + Clone
+    {
+        typed::iter_of(&self.table)
+    }
+    
+    /// Iterates over mutable references to each argument of type T, skipping the others.
+    ///
+    /// Borrowed arguments of type T become owned, as with Argument::to_mut.
+    #[inline(always)]
+    pub fn iter_of_mut<T>(&mut self) -> impl Iterator<Item = &mut T>
+    where
+        T: Any + Clone
+    {
+        typed::iter_of_mut(&mut self.table)
+    }
+    
+    /// Consumes the builder, moving out each argument of type T.
+    ///
+    /// Owned arguments are moved through downcast_owned, while borrowed ones get cloned.
+    #[inline(always)]
+    pub fn into_iter_of<T>(self) -> impl Iterator<Item = T>
+    where
+        T: Any + Clone
+    {
+        typed::into_iter_of(self.table.into_iter())
+    }
+    
+    /// Returns a reference to the first argument of type T.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of.
+    #[inline(always)]
+    pub fn find<T>(&self) -> Option<&T>
+    where
+        T: Any + Clone
+    {
+        self.iter_of::<T>().next()
+    }
+    
+    /// Returns the index of the first argument of type T, which is the one find returns.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of. Use
+    /// Argument::value_type_id to match them by the type they promote into.
+    #[inline(always)]
+    pub fn position_of<T>(&self) -> Option<usize>
+    where
+        T: Any + Clone
+    {
+        typed::position_of::<T>(&self.table)
+    }
+    
+    /// Counts the arguments of type T, which are the ones iter_of yields.
+    ///
+    /// Borrowed str and `[T]` slices are skipped, as in iter_of.
+    #[inline(always)]
+    pub fn count_of<T>(&self) -> usize
+    where
+        T: Any + Clone
+    {
+        typed::count_of::<T>(&self.table)
+    }
+    
+    /// Records the outcome of a chained insertion.
+    #[inline(always)]
+    fn chain(mut self, inserted: bool) -> Self
+    {
+        if !inserted
+        {
+            match &mut self.overflow
+            {
+                Some(e) => e.rejected += 1,
+                None => self.overflow = Some(CapacityError { index: self.chained, rejected: 1 })
+            }
+        }
+        
+        self.chained += 1;
+        
+        self
+    }
+    
+    /// Adds an owned item, returning the builder for further chaining.
+    ///
+    /// If the builder is full, the item is dropped, and the failure is reported by try_build.
+    #[inline(always)]
+    pub fn arg<T>(mut self, owned: T) -> Self
+    where
+        T: Any + Clone
+    {
+        let inserted = self.insert_owned(owned).is_ok();
+        
+        self.chain(inserted)
+    }
+    
+    /// Adds a borrowed item, returning the builder for further chaining.
+    ///
+    /// If the builder is full, the failure is reported by try_build.
+    #[inline(always)]
+    pub fn arg_ref<T>(mut self, borrowed: &'a T) -> Self
+    where
+        T: Any + Clone
+    {
+        let inserted = self.insert_borrowed(borrowed);
+        
+        self.chain(inserted)
+    }
+    
+    /// Adds a borrowed string slice, returning the builder for further chaining.
+    ///
+    /// If the builder is full, the failure is reported by try_build.
+    #[inline(always)]
+    pub fn arg_str(mut self, borrowed: &'a str) -> Self
+    {
+        let inserted = self.insert_borrowed_str(borrowed);
+        
+        self.chain(inserted)
+    }
+    
+    /// Adds an argument, returning the builder for further chaining.
+    ///
+    /// If the builder is full, the argument is dropped, and the failure is reported by try_build.
+    #[inline(always)]
+    pub fn arg_argument(mut self, arg: Argument<'a>) -> Self
+    {
+        let inserted = self.insert_argument(arg).is_ok();
+        
+        self.chain(inserted)
+    }
+    
+    /// Builds the inner argument table, reporting any chained insertion that did not fit.
+    ///
+    /// # Return values
+    /// Ok(args): Every chained insertion succeeded.
+    /// Err(e): At least one chained insertion was rejected. The error names the
+    /// first rejected insertion, counted from zero across every chaining call.
+    ///
+    /// ```
+    /// use variadic_arguments::ArgumentsBuilder;
+    ///
+    /// let x = String::from("borrowed");
+    ///
+    /// let args =
+    /// ArgumentsBuilder::new().arg(1_i32)
+    ///                        .arg_ref(&x)
+    ///                        .arg_str("text")
+    ///                        .try_build()?;
+    ///
+    /// assert_eq!(args.len(), 3);
+    /// # Ok::<(), variadic_arguments::CapacityError>(())
+    /// ```
+    #[inline(always)]
+    pub fn try_build(self) -> Result<Arguments<'a>, CapacityError>
+    {
+        match self.overflow
+        {
+            Some(e) => Err(e),
+            None => Ok(self.build())
+        }
+    }
+    
     /// Builds the inner argument table, returning Arguments in exchange.
+    ///
+    /// Chained insertions that did not fit are silently left out. Use try_build
+    /// to report them instead.
     #[inline(always)]
     pub fn build(self) -> Arguments<'a>
     {
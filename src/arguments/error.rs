@@ -0,0 +1,57 @@
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+use super::{Arguments, MAX_ARG_COUNT};
+
+/// An error raised when arguments do not fit within MAX_ARG_COUNT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError
+{
+    /// The zero-based index of the first argument that did not fit,
+    /// counted across the arguments that were being added.
+    pub index: usize,
+    /// The amount of arguments that did not fit.
+    pub rejected: usize
+}
+
+impl fmt::Display for CapacityError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f,
+               "argument {} exceeds the limit of {} arguments ({} rejected)",
+               self.index,
+               MAX_ARG_COUNT,
+               self.rejected)
+    }
+}
+
+impl error::Error for CapacityError {}
+
+/// An error raised when joining two sets of arguments exceeds MAX_ARG_COUNT.
+///
+/// Both sets are handed back unchanged.
+#[derive(Debug)]
+pub struct JoinError<'a>
+{
+    /// The capacity error, whose index is counted across the arguments being added.
+    pub error: CapacityError,
+    /// The set that would have come first.
+    pub first: Arguments<'a>,
+    /// The set that would have come second.
+    pub second: Arguments<'a>
+}
+
+impl fmt::Display for JoinError<'_>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl error::Error for JoinError<'_> {}
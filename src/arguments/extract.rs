@@ -0,0 +1,56 @@
+#[cfg(no_std)]
+use core::any::Any;
+
+#[cfg(not(no_std))]
+use std::any::Any;
+
+use crate::{Argument, Coercion};
+
+/// A tuple whose elements can be extracted from a set of arguments.
+///
+/// This is implemented for tuples of up to 8 elements, each of which
+/// implements Any and Clone.
+pub trait FromArguments : Sized
+{
+    /// The amount of arguments the tuple is extracted from.
+    const LEN : usize;
+    
+    /// Extracts the tuple, cloning each argument into its element's type.
+    ///
+    /// Returns None if the argument count differs from LEN, or if any
+    /// argument does not convert into its element's type under coercion.
+    fn from_arguments(args: &[Argument<'_>], coercion: Coercion) -> Option<Self>;
+}
+
+macro_rules! from_arguments_tuple
+{
+    ($len:literal; $($t:ident => $idx:tt),+) =>
+    {
+        impl<$($t),+> FromArguments for ($($t,)+)
+        where
+            $($t: Any + Clone),+
+        {
+            const LEN : usize = $len;
+            
+            #[inline(always)]
+            fn from_arguments(args: &[Argument<'_>], coercion: Coercion) -> Option<Self>
+            {
+                if args.len() != $len
+                {
+                    return None;
+                }
+                
+                Some(($(args[$idx].coerce_with::<$t>(coercion)?,)+))
+            }
+        }
+    };
+}
+
+from_arguments_tuple!(1; A => 0);
+from_arguments_tuple!(2; A => 0, B => 1);
+from_arguments_tuple!(3; A => 0, B => 1, C => 2);
+from_arguments_tuple!(4; A => 0, B => 1, C => 2, D => 3);
+from_arguments_tuple!(5; A => 0, B => 1, C => 2, D => 3, E => 4);
+from_arguments_tuple!(6; A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+from_arguments_tuple!(7; A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6);
+from_arguments_tuple!(8; A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7);
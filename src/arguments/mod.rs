@@ -1,8 +1,15 @@
 mod args;
+mod args_ref;
 mod builder;
+mod error;
+mod extract;
+mod typed;
 
 /// The maximum amount of arguments allowed inside a arguments container.
 pub const MAX_ARG_COUNT : usize = 1024;
 
 pub use args::Arguments;
+pub use args_ref::ArgumentsRef;
 pub use builder::ArgumentsBuilder;
+pub use error::{CapacityError, JoinError};
+pub use extract::FromArguments;
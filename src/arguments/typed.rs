@@ -0,0 +1,82 @@
+#[cfg(no_std)]
+use core::any::{Any, TypeId};
+
+#[cfg(not(no_std))]
+use std::any::{Any, TypeId};
+
+use crate::Argument;
+
+/// Checks if an argument can be read as T by reference.
+///
+/// Borrowed str and `[T]` slices cannot be read as String or `Vec<T>` by
+/// reference, so they do not match, even though their value type does.
+#[inline(always)]
+pub(super) fn is_of<T>(arg: &Argument<'_>) -> bool
+where
+    T: Any + Clone
+{
+    arg.is::<T>()
+}
+
+/// Iterates over references to each argument of type T, as matched by is_of.
+#[inline(always)]
+pub(super) fn iter_of<'b, T>(table: &'b [Argument<'_>]) -> impl Iterator<Item = &'b T>
+where
+    T: Any + Clone
+{
+    table.iter()
+         .filter(|a| is_of::<T>(a))
+         .filter_map(|a| a.downcast_ref::<T>())
+}
+
+/// Returns the index of the first argument of type T, as matched by is_of.
+#[inline(always)]
+pub(super) fn position_of<T>(table: &[Argument<'_>]) -> Option<usize>
+where
+    T: Any + Clone
+{
+    table.iter()
+         .position(is_of::<T>)
+}
+
+/// Counts the arguments of type T, as matched by is_of.
+#[inline(always)]
+pub(super) fn count_of<T>(table: &[Argument<'_>]) -> usize
+where
+    T: Any + Clone
+{
+    table.iter()
+         .filter(|a| is_of::<T>(a))
+         .count()
+}
+
+/// Iterates over mutable references to each argument of type T.
+///
+/// Borrowed arguments of type T are cloned first, as with Argument::to_mut,
+/// which promotes borrowed slices into String and `Vec<T>`.
+#[inline(always)]
+pub(super) fn iter_of_mut<'b, T>(table: &'b mut [Argument<'_>]) -> impl Iterator<Item = &'b mut T>
+where
+    T: Any + Clone
+{
+    table.iter_mut()
+         .filter(|a| a.value_type_id() == TypeId::of::<T>())
+         .filter_map(|a| a.to_mut().downcast_mut::<T>())
+}
+
+/// Moves each argument of type T out, cloning the borrowed ones.
+///
+/// Borrowed slices are promoted into String and `Vec<T>`.
+#[inline(always)]
+pub(super) fn into_iter_of<'a, T, I>(table: I) -> impl Iterator<Item = T>
+where
+    T: Any + Clone,
+    I: Iterator<Item = Argument<'a>>
+{
+    table.filter_map(|a|
+    match a.downcast_owned::<T>()
+    {
+        Ok(t) => Some(t),
+        Err(a) => a.downcast_cloned::<T>()
+    })
+}
@@ -0,0 +1,27 @@
+use std::{error, fmt};
+
+/// An error raised when an argument cannot be rendered for a command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArgvError
+{
+    /// The positional argument at position has no built-in rendering.
+    Unsupported { position: usize },
+    /// The keyword argument under key has no built-in rendering.
+    UnsupportedKey { key: Box<str> }
+}
+
+impl fmt::Display for ArgvError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Unsupported { position } =>
+            write!(f, "argument {position} cannot be rendered as a command line argument"),
+            Self::UnsupportedKey { key } =>
+            write!(f, "argument '{key}' cannot be rendered as a command line argument")
+        }
+    }
+}
+
+impl error::Error for ArgvError {}
@@ -0,0 +1,7 @@
+mod error;
+mod render;
+mod to_argv;
+
+pub use error::ArgvError;
+pub use render::FlagStyle;
+pub use to_argv::ToArgv;
@@ -0,0 +1,210 @@
+use std::{
+    any::Any,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    process::Command
+};
+
+use crate::{text, Argument, Arguments, KeywordArguments};
+
+use super::{ArgvError, ToArgv};
+
+/// How keyword arguments are rendered as flags.
+///
+/// Flags whose value is a bool render as the flag alone when true,
+/// and are left out when false, regardless of the style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlagStyle
+{
+    /// `--key=value`
+    #[default]
+    LongEquals,
+    /// `--key value`
+    LongSeparate,
+    /// `-k value` for single character keys, and `--key value` for the rest.
+    Short
+}
+
+/// Renders an argument through the built-in ToArgv implementations.
+///
+/// Text is rendered through the table shared with ToText, followed by the path and
+/// OS string types.
+fn render_builtin(arg: &Argument<'_>) -> Option<OsString>
+{
+    if let Some(text) = text::render_builtin(arg)
+    {
+        return Some(OsString::from(text));
+    }
+    
+    macro_rules! builtins
+    {
+        ($($t:ty),*) =>
+        {
+            $(
+                if let Some(v) = arg.downcast_ref::<$t>()
+                {
+                    return Some(v.to_argv());
+                }
+            )*
+        };
+    }
+    
+    builtins!(PathBuf, OsString, Box<Path>, Box<OsStr>, &'static Path, &'static OsStr);
+    
+    None
+}
+
+impl Arguments<'_>
+{
+    /// Renders each argument as a command line argument.
+    ///
+    /// Supported types are the ones implementing ToArgv within this crate, along
+    /// with String, `&'static str` and the other types read by Argument::downcast_str.
+    ///
+    /// # Return values
+    /// Ok(argv): Every argument was rendered.
+    /// Err(e): The position of the first argument that is not supported.
+    #[inline(always)]
+    pub fn to_argv(&self) -> Result<Vec<OsString>, ArgvError>
+    {
+        self.to_argv_with(|_| None)
+    }
+    
+    /// Renders each argument as a command line argument, with a fallback for other types.
+    ///
+    /// The fallback is called for arguments without a built-in rendering, and
+    /// usually downcasts the value before calling ToArgv on it.
+    ///
+    /// # Return values
+    /// Refer to Arguments::to_argv.
+    pub fn to_argv_with<F>(&self, fallback: F) -> Result<Vec<OsString>, ArgvError>
+    where
+        F: Fn(&dyn Any) -> Option<OsString>
+    {
+        self.iter()
+            .enumerate()
+            .map(|(position, arg)|
+                 render_builtin(arg)
+                    .or_else(|| fallback(&**arg))
+                    .ok_or(ArgvError::Unsupported { position }))
+            .collect()
+    }
+}
+
+impl KeywordArguments<'_>
+{
+    /// Renders each keyword argument as a flag, in insertion order.
+    ///
+    /// Values are rendered as in Arguments::to_argv.
+    ///
+    /// # Return values
+    /// Ok(argv): Every keyword argument was rendered.
+    /// Err(e): The key of the first argument that is not supported.
+    #[inline(always)]
+    pub fn to_flags(&self, style: FlagStyle) -> Result<Vec<OsString>, ArgvError>
+    {
+        self.to_flags_with(style, |_| None)
+    }
+    
+    /// Renders each keyword argument as a flag, with a fallback for other types.
+    ///
+    /// The fallback is called as in Arguments::to_argv_with.
+    ///
+    /// # Return values
+    /// Refer to KeywordArguments::to_flags.
+    pub fn to_flags_with<F>(&self, style: FlagStyle, fallback: F) -> Result<Vec<OsString>, ArgvError>
+    where
+        F: Fn(&dyn Any) -> Option<OsString>
+    {
+        let mut output = Vec::with_capacity(self.len() * 2);
+        
+        for (key, arg) in self.iter()
+        {
+            let short = style == FlagStyle::Short && key.chars().count() == 1;
+            
+            let flag =
+            if short { OsString::from(format!("-{key}")) }
+            else { OsString::from(format!("--{key}")) };
+            
+            if let Some(switch) = arg.downcast_ref::<bool>()
+            {
+                if *switch
+                {
+                    output.push(flag);
+                }
+                
+                continue;
+            }
+            
+            let value =
+            render_builtin(arg)
+                .or_else(|| fallback(&**arg))
+                .ok_or_else(|| ArgvError::UnsupportedKey { key: key.into() })?;
+            
+            if style == FlagStyle::LongEquals
+            {
+                let mut joined = flag;
+                
+                joined.push("=");
+                joined.push(value);
+                
+                output.push(joined);
+            }
+            else
+            {
+                output.push(flag);
+                output.push(value);
+            }
+        }
+        
+        Ok(output)
+    }
+    
+    /// Builds a process command, passing the keyword arguments as flags,
+    /// followed by the positional arguments.
+    ///
+    /// If any positional argument starts with `-`, the positional arguments
+    /// are preceded by `--`, so they are not mistaken for flags.
+    ///
+    /// # Return values
+    /// Ok(command): The command, which has not been spawned.
+    /// Err(e): Refer to KeywordArguments::to_flags and Arguments::to_argv.
+    #[inline(always)]
+    pub fn to_command<P>(&self, program: P, positional: &Arguments<'_>, style: FlagStyle)
+    -> Result<Command, ArgvError>
+    where
+        P: AsRef<OsStr>
+    {
+        self.to_command_with(program, positional, style, |_| None)
+    }
+    
+    /// Builds a process command, with a fallback for other types.
+    ///
+    /// The fallback is called for both flags and positional arguments,
+    /// as in Arguments::to_argv_with.
+    ///
+    /// # Return values
+    /// Refer to KeywordArguments::to_command.
+    pub fn to_command_with<P, F>(&self, program: P, positional: &Arguments<'_>, style: FlagStyle, fallback: F)
+    -> Result<Command, ArgvError>
+    where
+        P: AsRef<OsStr>,
+        F: Fn(&dyn Any) -> Option<OsString>
+    {
+        let flags = self.to_flags_with(style, &fallback)?;
+        let positional = positional.to_argv_with(&fallback)?;
+        
+        let mut command = Command::new(program);
+        
+        command.args(flags);
+        
+        if positional.iter().any(|a| a.as_encoded_bytes().starts_with(b"-"))
+        {
+            command.arg("--");
+        }
+        
+        command.args(positional);
+        
+        Ok(command)
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf}
+};
+
+use crate::ToText;
+
+/// Renders a value as a single command line argument.
+///
+/// Every ToText type renders as its text. bool renders as `true` or `false`.
+/// When used as a flag, true only renders the flag itself, while false leaves the flag out.
+pub trait ToArgv
+{
+    /// Renders the value.
+    fn to_argv(&self) -> OsString;
+}
+
+impl<T> ToArgv for T
+where
+    T: ToText + ?Sized
+{
+    #[inline(always)]
+    fn to_argv(&self) -> OsString
+    {
+        OsString::from(self.to_text())
+    }
+}
+
+macro_rules! to_argv_os
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl ToArgv for $t
+            {
+                #[inline(always)]
+                fn to_argv(&self) -> OsString
+                {
+                    AsRef::<OsStr>::as_ref(self).to_owned()
+                }
+            }
+        )*
+    };
+}
+
+to_argv_os!(OsStr, OsString, Path, PathBuf);
+
+macro_rules! to_argv_deref
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl ToArgv for $t
+            {
+                #[inline(always)]
+                fn to_argv(&self) -> OsString
+                {
+                    (**self).to_argv()
+                }
+            }
+        )*
+    };
+}
+
+to_argv_deref!(&OsStr, &OsString, &Path, &PathBuf,
+               Box<OsStr>, Box<Path>, Cow<'_, OsStr>, Cow<'_, Path>);
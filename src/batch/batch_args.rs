@@ -0,0 +1,386 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::{
+    any::{Any, TypeId},
+    fmt
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{Any, TypeId},
+    fmt
+};
+
+use crate::{Argument, Arguments, Coercion, Signature, SignatureError, ViolationKind, MAX_ARG_COUNT};
+
+use super::{column::ErasedColumn, BatchError, Column};
+
+/// A set of argument rows, stored column by column.
+///
+/// Each column is a typed vector, so every row shares the same types. Rows are
+/// handed out as Arguments whose items borrow from the columns, which avoids
+/// cloning the values of each row.
+///
+/// Types are checked once, when columns are added or when the batch is validated,
+/// rather than once per row.
+pub struct ArgumentsBatch
+{
+    /// The columns, each of which holds one value per row.
+    columns: Vec<Box<dyn Column>>,
+    /// The amount of rows, which every column must match.
+    rows: usize
+}
+
+impl fmt::Debug for ArgumentsBatch
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("ArgumentsBatch")
+         .field("columns", &self.columns.len())
+         .field("rows", &self.rows)
+         .finish()
+    }
+}
+
+impl Clone for ArgumentsBatch
+{
+    #[inline(always)]
+    fn clone(&self) -> Self
+    {
+        Self
+        {
+            columns: self.columns
+                         .iter()
+                         .map(|c| c.clone_column())
+                         .collect(),
+            rows: self.rows
+        }
+    }
+}
+
+impl Default for ArgumentsBatch
+{
+    #[inline(always)]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl ArgumentsBatch
+{
+    /// Creates an empty batch, with neither rows nor columns.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self
+        {
+            columns: Vec::new(),
+            rows: 0
+        }
+    }
+    
+    /// Creates a batch from a set of row-wise arguments.
+    ///
+    /// The first row decides the type of each column. Each value gets cloned into
+    /// its column, and borrowed slices are promoted into String or `Vec<T>`.
+    ///
+    /// As the types are only known at runtime, these columns store one value per
+    /// OwnedArgument. Use column_mut in order to turn one into a contiguous `Vec<T>`.
+    ///
+    /// # Return values
+    /// Ok(Self): Every row has the same length and types.
+    /// Err(BatchError::RowLength): A row has a different length than the first.
+    /// Err(BatchError::ColumnType): A row has a value of a different type than the first.
+    pub fn from_rows(rows: &[Arguments<'_>]) -> Result<Self, BatchError>
+    {
+        let Some((first, rest)) = rows.split_first()
+        else
+        {
+            return Ok(Self::new());
+        };
+        
+        let mut columns : Vec<Box<dyn Column>> =
+        first.iter()
+             .map(|a| Box::new(ErasedColumn::new(a.handle())) as Box<dyn Column>)
+             .collect();
+        
+        for (idx, row) in rest.iter().enumerate()
+        {
+            if row.len() != columns.len()
+            {
+                return Err(BatchError::RowLength
+                {
+                    row: idx + 1,
+                    expected: columns.len(),
+                    found: row.len()
+                });
+            }
+            
+            for (column, (target, arg)) in columns.iter_mut().zip(row.iter()).enumerate()
+            {
+                if !target.push_value(arg.handle())
+                {
+                    return Err(BatchError::ColumnType { row: Some(idx + 1), column });
+                }
+            }
+        }
+        
+        Ok(Self
+        {
+            columns,
+            rows: rows.len()
+        })
+    }
+    
+    /// Adds a column to the batch.
+    ///
+    /// The first column decides the amount of rows.
+    ///
+    /// # Return values
+    /// Ok(()): The column was added.
+    /// Err(values): The column's length does not match the amount of rows, or the
+    /// batch already has MAX_ARG_COUNT columns.
+    #[inline(always)]
+    pub fn push_column<T>(&mut self, values: Vec<T>) -> Result<(), Vec<T>>
+    where
+        T: Any + Clone
+    {
+        if self.columns.len() >= MAX_ARG_COUNT
+        || (!self.columns.is_empty() && values.len() != self.rows)
+        {
+            return Err(values);
+        }
+        
+        self.rows = values.len();
+        self.columns.push(Box::new(values));
+        
+        Ok(())
+    }
+    
+    /// Adds a column to the batch, returning the batch.
+    ///
+    /// # Return values
+    /// Refer to ArgumentsBatch::push_column. The batch is dropped on failure.
+    #[inline(always)]
+    pub fn with_column<T>(mut self, values: Vec<T>) -> Result<Self, Vec<T>>
+    where
+        T: Any + Clone
+    {
+        self.push_column(values)?;
+        
+        Ok(self)
+    }
+    
+    /// Returns the amount of rows.
+    #[inline(always)]
+    pub fn rows(&self) -> usize
+    {
+        self.rows
+    }
+    
+    /// Returns the amount of columns.
+    #[inline(always)]
+    pub fn columns(&self) -> usize
+    {
+        self.columns.len()
+    }
+    
+    /// Checks if the batch has no rows.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.rows == 0
+    }
+    
+    /// Iterates over the type of each column.
+    #[inline(always)]
+    pub fn column_types(&self) -> impl Iterator<Item = TypeId> + '_
+    {
+        self.columns
+            .iter()
+            .map(|c| c.element_type())
+    }
+    
+    /// Acquires a column as a typed slice.
+    ///
+    /// Returns None if there is no such column, or if it is not of type T. Columns
+    /// built through from_rows are not contiguous until column_mut is called on them,
+    /// so this returns None for those as well.
+    #[inline(always)]
+    pub fn column<T>(&self, idx: usize) -> Option<&[T]>
+    where
+        T: Any + Clone
+    {
+        self.columns
+            .get(idx)?
+            .as_any()
+            .downcast_ref::<Vec<T>>()
+            .map(Vec::as_slice)
+    }
+    
+    /// Acquires a column as a mutable, typed slice.
+    ///
+    /// Columns built through from_rows are moved into a `Vec<T>` first, which
+    /// then lets column read them as well.
+    ///
+    /// Returns None if there is no such column, or if it is not of type T.
+    pub fn column_mut<T>(&mut self, idx: usize) -> Option<&mut [T]>
+    where
+        T: Any + Clone
+    {
+        let column = self.columns.get_mut(idx)?;
+        
+        if let Some(erased) = column.as_any_mut().downcast_mut::<ErasedColumn>()
+        {
+            *column = Box::new(erased.take_typed::<T>()?);
+        }
+        
+        column.as_any_mut()
+              .downcast_mut::<Vec<T>>()
+              .map(Vec::as_mut_slice)
+    }
+    
+    /// Checks the column types against a signature, once for the entire batch.
+    ///
+    /// # Return values
+    /// Ok(()): There is one column per type, and each column matches its type.
+    /// Err(BatchError::ColumnCount): The amount of columns does not match the signature.
+    /// Err(BatchError::ColumnType): The first column which does not match.
+    pub fn validate(&self, signature: &[TypeId]) -> Result<(), BatchError>
+    {
+        if signature.len() != self.columns.len()
+        {
+            return Err(BatchError::ColumnCount
+            {
+                expected: signature.len(),
+                found: self.columns.len()
+            });
+        }
+        
+        match self.column_types()
+                  .zip(signature)
+                  .position(|(found, expected)| found != *expected)
+        {
+            Some(column) => Err(BatchError::ColumnType { row: None, column }),
+            None => Ok(())
+        }
+    }
+    
+    /// Checks the columns against a signature.
+    ///
+    /// Column types are checked once for the entire batch, as in ArgumentsBatch::validate.
+    /// Parameters accepting any type match any column, and the variadic tail covers the
+    /// columns past the fixed parameters. Rows are only checked one by one when the
+    /// signature has constraints, or when a column has to be coerced into its type.
+    ///
+    /// # Return values
+    /// Ok(()): Every row matches the signature.
+    /// Err(BatchError::ColumnCount): The amount of columns does not fit the signature.
+    /// Err(BatchError::ColumnType): The first column, or value if coerced, which does not match.
+    /// Err(BatchError::Constraint): The first value violating a constraint.
+    pub fn validate_signature(&self, signature: &Signature) -> Result<(), BatchError>
+    {
+        if !signature.accepts_len(self.columns.len())
+        {
+            return Err(BatchError::ColumnCount
+            {
+                expected: signature.len(),
+                found: self.columns.len()
+            });
+        }
+        
+        let mut coerced = false;
+        
+        for (column, found) in self.column_types().enumerate()
+        {
+            match signature.type_at(column)
+            {
+                Some(expected) if expected != found =>
+                {
+                    if let Coercion::Exact = signature.coercion()
+                    {
+                        return Err(BatchError::ColumnType { row: None, column });
+                    }
+                    
+                    coerced = true;
+                }
+                _ => ()
+            }
+        }
+        
+        if !coerced && !signature.has_constraints()
+        {
+            return Ok(());
+        }
+        
+        for (row, args) in self.iter_rows().enumerate()
+        {
+            // The column count has already been checked, so only violations remain.
+            if let Err(SignatureError::Violations(violations)) = signature.validate(&args)
+            {
+                let column = violations[0].position;
+                
+                return match violations[0].kind
+                {
+                    ViolationKind::Type { .. } => Err(BatchError::ColumnType { row: Some(row), column }),
+                    ViolationKind::Constraint { .. } => Err(BatchError::Constraint { row, column })
+                };
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Acquires a row as a set of borrowed arguments.
+    ///
+    /// Returns None if the row is out of bounds.
+    #[inline(always)]
+    pub fn row(&self, idx: usize) -> Option<Arguments<'_>>
+    {
+        if idx >= self.rows
+        {
+            return None;
+        }
+        
+        let row =
+        Arguments::create_from_iter(self.columns
+                                        .iter()
+                                        .map(|c| Argument::from_handle(c.get(idx).unwrap())));
+        
+        // Columns are capped at MAX_ARG_COUNT when added.
+        row.ok()
+    }
+    
+    /// Iterates over each row, as sets of borrowed arguments.
+    #[inline(always)]
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = Arguments<'_>> + '_
+    {
+        (0..self.rows).map(|idx| self.row(idx).unwrap())
+    }
+    
+    /// Calls a function once per row, collecting the results.
+    ///
+    /// As with iter_rows, the arguments borrow from the batch.
+    #[inline(always)]
+    pub fn map_rows<F, R>(&self, f: F) -> Vec<R>
+    where
+        F: FnMut(Arguments<'_>) -> R
+    {
+        self.iter_rows()
+            .map(f)
+            .collect()
+    }
+    
+    /// Converts the batch back into row-wise arguments, which own their values.
+    #[inline(always)]
+    pub fn into_rows(self) -> Vec<Arguments<'static>>
+    {
+        self.iter_rows()
+            .map(Arguments::into_owned)
+            .collect()
+    }
+}
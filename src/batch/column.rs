@@ -0,0 +1,191 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+#[cfg(no_std)]
+use core::any::{Any, TypeId};
+
+#[cfg(not(no_std))]
+use std::any::{Any, TypeId};
+
+use crate::{argument::VariantHandle, OwnedArgument};
+
+/// A type-erased column of values, which all share one type.
+pub(crate) trait Column
+{
+    /// Returns the TypeId of the values.
+    fn element_type(&self) -> TypeId;
+    
+    /// Acquires a handle to the value in the specified row.
+    fn get(&self, row: usize) -> Option<&dyn VariantHandle>;
+    
+    /// Clones a value into the column.
+    ///
+    /// Borrowed slices are promoted, as with clone_object. Returns false,
+    /// without pushing anything, if the value is of another type.
+    fn push_value(&mut self, value: &dyn VariantHandle) -> bool;
+    
+    /// Clones the column itself.
+    fn clone_column(&self) -> Box<dyn Column>;
+    
+    /// Provides access to the column, which is either a `Vec<T>` or an ErasedColumn.
+    fn as_any(&self) -> &dyn Any;
+    
+    /// Provides mutable access to the column, as with as_any.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> Column for Vec<T>
+where
+    T: Any + Clone
+{
+    #[inline(always)]
+    fn element_type(&self) -> TypeId
+    {
+        TypeId::of::<T>()
+    }
+    
+    #[inline(always)]
+    fn get(&self, row: usize) -> Option<&dyn VariantHandle>
+    {
+        self.as_slice()
+            .get(row)
+            .map(|v| v as &dyn VariantHandle)
+    }
+    
+    #[inline(always)]
+    fn push_value(&mut self, value: &dyn VariantHandle) -> bool
+    {
+        if value.value_type_id() != TypeId::of::<T>()
+        {
+            return false;
+        }
+        
+        match value.clone_object().downcast_owned::<T>()
+        {
+            Ok(v) =>
+            {
+                self.push(v);
+                true
+            }
+            Err(_) => false
+        }
+    }
+    
+    #[inline(always)]
+    fn clone_column(&self) -> Box<dyn Column>
+    {
+        Box::new(self.clone())
+    }
+    
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any
+    {
+        self
+    }
+    
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    {
+        self
+    }
+}
+
+/// A column whose type is only known at runtime, storing one OwnedArgument per value.
+///
+/// This is what ArgumentsBatch::from_rows builds, as there is no `Vec<T>` to create
+/// from a TypeId. It can be turned into one once T is known, through take_typed.
+pub(crate) struct ErasedColumn
+{
+    element_type: TypeId,
+    values: Vec<OwnedArgument>
+}
+
+impl ErasedColumn
+{
+    /// Creates a column holding a clone of the value.
+    #[inline(always)]
+    pub fn new(value: &dyn VariantHandle) -> Self
+    {
+        Self
+        {
+            element_type: value.value_type_id(),
+            values: vec![value.clone_object()]
+        }
+    }
+    
+    /// Moves the values into a `Vec<T>`.
+    ///
+    /// Returns None, leaving the column intact, if the values are not of type T.
+    #[inline(always)]
+    pub fn take_typed<T>(&mut self) -> Option<Vec<T>>
+    where
+        T: Any + Clone
+    {
+        if self.element_type != TypeId::of::<T>()
+        {
+            return None;
+        }
+        
+        // Safety: Every value has been checked to be of type T when pushed.
+        let output =
+        self.values
+            .drain(..)
+            .map(|v| unsafe { v.downcast_owned_unchecked() })
+            .collect();
+        
+        Some(output)
+    }
+}
+
+impl Column for ErasedColumn
+{
+    #[inline(always)]
+    fn element_type(&self) -> TypeId
+    {
+        self.element_type
+    }
+    
+    #[inline(always)]
+    fn get(&self, row: usize) -> Option<&dyn VariantHandle>
+    {
+        self.values
+            .as_slice()
+            .get(row)
+            .map(OwnedArgument::raw_ref)
+    }
+    
+    #[inline(always)]
+    fn push_value(&mut self, value: &dyn VariantHandle) -> bool
+    {
+        if value.value_type_id() != self.element_type
+        {
+            return false;
+        }
+        
+        self.values.push(value.clone_object());
+        
+        true
+    }
+    
+    #[inline(always)]
+    fn clone_column(&self) -> Box<dyn Column>
+    {
+        Box::new(Self
+        {
+            element_type: self.element_type,
+            values: self.values.clone()
+        })
+    }
+    
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any
+    {
+        self
+    }
+    
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    {
+        self
+    }
+}
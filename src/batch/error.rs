@@ -0,0 +1,43 @@
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+/// An error raised while building or validating an ArgumentsBatch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchError
+{
+    /// A row does not have as many arguments as the batch has columns.
+    RowLength { row: usize, expected: usize, found: usize },
+    /// The batch does not have as many columns as there are types or parameters to validate against.
+    ColumnCount { expected: usize, found: usize },
+    /// A value's type does not match the type of its column.
+    ///
+    /// When validating against a list of types, row is None.
+    ColumnType { row: Option<usize>, column: usize },
+    /// A value violates a constraint of its column's parameter.
+    Constraint { row: usize, column: usize }
+}
+
+impl fmt::Display for BatchError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::RowLength { row, expected, found } =>
+            write!(f, "row {row} has {found} arguments, expected {expected}"),
+            Self::ColumnCount { expected, found } =>
+            write!(f, "batch has {found} columns, expected {expected}"),
+            Self::ColumnType { row: Some(row), column } =>
+            write!(f, "row {row} does not match the type of column {column}"),
+            Self::ColumnType { row: None, column } =>
+            write!(f, "column {column} does not match the expected type"),
+            Self::Constraint { row, column } =>
+            write!(f, "row {row} violates a constraint of column {column}")
+        }
+    }
+}
+
+impl error::Error for BatchError {}
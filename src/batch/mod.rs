@@ -0,0 +1,7 @@
+mod batch_args;
+mod column;
+mod error;
+
+pub use batch_args::ArgumentsBatch;
+pub(crate) use column::Column;
+pub use error::BatchError;
@@ -0,0 +1,86 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+/// An error raised when a parameter cannot be added to a ParamSpec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamSpecError
+{
+    /// The name is already used by another parameter or collector.
+    DuplicateName { name: Box<str> },
+    /// A required positional parameter follows one with a default value.
+    RequiredAfterDefault { name: Box<str> },
+    /// A positional parameter follows the `*rest` collector.
+    PositionalAfterRest { name: Box<str> }
+}
+
+impl fmt::Display for ParamSpecError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::DuplicateName { name } =>
+            write!(f, "duplicate argument '{name}' in parameter list"),
+            Self::RequiredAfterDefault { name } =>
+            write!(f, "parameter '{name}' without a default follows a parameter with a default"),
+            Self::PositionalAfterRest { name } =>
+            write!(f, "positional parameter '{name}' follows the rest collector")
+        }
+    }
+}
+
+impl error::Error for ParamSpecError {}
+
+/// An error raised when arguments cannot be bound to a ParamSpec.
+///
+/// The variants follow the errors raised by Python when calling a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindError
+{
+    /// There are more positional arguments than positional parameters,
+    /// and there is no `*rest` collector.
+    TooManyPositional { expected: usize, found: usize },
+    /// An argument was given both positionally and by keyword.
+    MultipleValues { name: Box<str> },
+    /// A keyword does not name any parameter, and there is no `**extra` collector.
+    UnexpectedKeyword { name: Box<str> },
+    /// Parameters without a default did not receive an argument, in declaration order.
+    MissingRequired { names: Vec<Box<str>> }
+}
+
+impl fmt::Display for BindError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::TooManyPositional { expected, found } =>
+            write!(f, "takes {expected} positional arguments but {found} were given"),
+            Self::MultipleValues { name } =>
+            write!(f, "got multiple values for argument '{name}'"),
+            Self::UnexpectedKeyword { name } =>
+            write!(f, "got an unexpected keyword argument '{name}'"),
+            Self::MissingRequired { names } =>
+            {
+                write!(f, "missing {} required arguments:", names.len())?;
+                
+                for (idx, name) in names.iter().enumerate()
+                {
+                    let separator = if idx == 0 { " " } else { ", " };
+                    
+                    write!(f, "{separator}'{name}'")?;
+                }
+                
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for BindError {}
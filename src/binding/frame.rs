@@ -0,0 +1,73 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{Argument, Arguments, KeywordArguments};
+
+/// The result of binding arguments to a ParamSpec.
+///
+/// Every declared parameter has a value, in declaration order, with keyword-only
+/// parameters coming last. Defaults are cloned into the frame.
+#[derive(Clone, Debug)]
+pub struct BoundArguments<'a>
+{
+    pub(super) params: Vec<(Box<str>, Argument<'a>)>,
+    pub(super) rest: Arguments<'a>,
+    pub(super) extra: KeywordArguments<'a>
+}
+
+impl<'a> BoundArguments<'a>
+{
+    /// Acquires the value of a declared parameter.
+    #[inline(always)]
+    pub fn get(&self, name: &str) -> Option<&Argument<'a>>
+    {
+        self.params
+            .iter()
+            .find(|(n, _)| **n == *name)
+            .map(|(_, a)| a)
+    }
+    
+    /// Acquires the value of a declared parameter mutably.
+    #[inline(always)]
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Argument<'a>>
+    {
+        self.params
+            .iter_mut()
+            .find(|(n, _)| **n == *name)
+            .map(|(_, a)| a)
+    }
+    
+    /// Iterates over each declared parameter and its value.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Argument<'a>)>
+    {
+        self.params
+            .iter()
+            .map(|(n, a)| (&**n, a))
+    }
+    
+    /// The positional arguments collected by `*rest`.
+    ///
+    /// This is empty if the ParamSpec has no such collector.
+    #[inline(always)]
+    pub fn rest(&self) -> &Arguments<'a>
+    {
+        &self.rest
+    }
+    
+    /// The keyword arguments collected by `**extra`.
+    ///
+    /// This is empty if the ParamSpec has no such collector.
+    #[inline(always)]
+    pub fn extra(&self) -> &KeywordArguments<'a>
+    {
+        &self.extra
+    }
+    
+    /// Splits the frame into the declared parameters, `*rest` and `**extra`.
+    #[inline(always)]
+    pub fn into_parts(self) -> (Vec<(Box<str>, Argument<'a>)>, Arguments<'a>, KeywordArguments<'a>)
+    {
+        (self.params, self.rest, self.extra)
+    }
+}
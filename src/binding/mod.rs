@@ -0,0 +1,7 @@
+mod error;
+mod frame;
+mod spec;
+
+pub use error::{BindError, ParamSpecError};
+pub use frame::BoundArguments;
+pub use spec::ParamSpec;
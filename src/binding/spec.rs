@@ -0,0 +1,243 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::any::Any;
+
+#[cfg(not(no_std))]
+use std::any::Any;
+
+use crate::{Argument, Arguments, KeywordArguments};
+
+use super::{BindError, BoundArguments, ParamSpecError};
+
+/// A declared parameter.
+#[derive(Clone, Debug)]
+struct Param
+{
+    name: Box<str>,
+    default: Option<Argument<'static>>
+}
+
+/// A Python-style parameter list.
+///
+/// A spec consists of, in order:
+/// - positional parameters, which can also be passed by keyword,
+/// - an optional `*rest` collector for the remaining positional arguments,
+/// - keyword-only parameters,
+/// - an optional `**extra` collector for the remaining keyword arguments.
+///
+/// Any parameter may have a default value. As in Python, required positional
+/// parameters cannot follow ones with defaults, while keyword-only parameters
+/// can be required anywhere.
+#[derive(Clone, Debug, Default)]
+pub struct ParamSpec
+{
+    positional: Vec<Param>,
+    rest: Option<Box<str>>,
+    keyword_only: Vec<Param>,
+    extra: Option<Box<str>>
+}
+
+impl ParamSpec
+{
+    /// Creates an empty spec, which accepts no arguments.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+    
+    /// Checks if a name is already in use.
+    #[inline(always)]
+    fn contains(&self, name: &str) -> bool
+    {
+        self.positional.iter().chain(&self.keyword_only).any(|p| *p.name == *name)
+        || self.rest.as_deref() == Some(name)
+        || self.extra.as_deref() == Some(name)
+    }
+    
+    /// Fails if a name is already in use.
+    #[inline(always)]
+    fn check_name(&self, name: &str) -> Result<(), ParamSpecError>
+    {
+        if self.contains(name)
+        {
+            Err(ParamSpecError::DuplicateName { name: name.into() })
+        }
+        else { Ok(()) }
+    }
+    
+    /// Adds a positional parameter.
+    fn push_positional(mut self, name: &str, default: Option<Argument<'static>>)
+    -> Result<Self, ParamSpecError>
+    {
+        self.check_name(name)?;
+        
+        if self.rest.is_some() || !self.keyword_only.is_empty()
+        {
+            return Err(ParamSpecError::PositionalAfterRest { name: name.into() });
+        }
+        
+        if default.is_none() && self.positional.last().is_some_and(|p| p.default.is_some())
+        {
+            return Err(ParamSpecError::RequiredAfterDefault { name: name.into() });
+        }
+        
+        self.positional.push(Param { name: name.into(), default });
+        
+        Ok(self)
+    }
+    
+    /// Adds a keyword-only parameter.
+    fn push_keyword(mut self, name: &str, default: Option<Argument<'static>>)
+    -> Result<Self, ParamSpecError>
+    {
+        self.check_name(name)?;
+        
+        self.keyword_only.push(Param { name: name.into(), default });
+        
+        Ok(self)
+    }
+    
+    /// Adds a required positional parameter.
+    #[inline(always)]
+    pub fn param(self, name: &str) -> Result<Self, ParamSpecError>
+    {
+        self.push_positional(name, None)
+    }
+    
+    /// Adds a positional parameter with a default value.
+    #[inline(always)]
+    pub fn param_default<T>(self, name: &str, default: T) -> Result<Self, ParamSpecError>
+    where
+        T: Any + Clone
+    {
+        self.push_positional(name, Some(Argument::new_owned(default)))
+    }
+    
+    /// Adds a required keyword-only parameter.
+    #[inline(always)]
+    pub fn keyword(self, name: &str) -> Result<Self, ParamSpecError>
+    {
+        self.push_keyword(name, None)
+    }
+    
+    /// Adds a keyword-only parameter with a default value.
+    #[inline(always)]
+    pub fn keyword_default<T>(self, name: &str, default: T) -> Result<Self, ParamSpecError>
+    where
+        T: Any + Clone
+    {
+        self.push_keyword(name, Some(Argument::new_owned(default)))
+    }
+    
+    /// Sets the `*rest` collector, replacing any previous one.
+    ///
+    /// Parameters added afterwards must be keyword-only.
+    #[inline(always)]
+    pub fn rest(mut self, name: &str) -> Result<Self, ParamSpecError>
+    {
+        self.rest = None;
+        self.check_name(name)?;
+        self.rest = Some(name.into());
+        
+        Ok(self)
+    }
+    
+    /// Sets the `**extra` collector, replacing any previous one.
+    #[inline(always)]
+    pub fn extra(mut self, name: &str) -> Result<Self, ParamSpecError>
+    {
+        self.extra = None;
+        self.check_name(name)?;
+        self.extra = Some(name.into());
+        
+        Ok(self)
+    }
+    
+    /// Iterates over the name of each declared parameter, with keyword-only parameters last.
+    ///
+    /// The collectors are not included.
+    #[inline(always)]
+    pub fn names(&self) -> impl Iterator<Item = &str>
+    {
+        self.positional
+            .iter()
+            .chain(&self.keyword_only)
+            .map(|p| &*p.name)
+    }
+    
+    /// Binds positional and keyword arguments to the parameters.
+    ///
+    /// Positional arguments fill the positional parameters in order, with the
+    /// remainder going to `*rest`. Keywords then fill the parameters of the same
+    /// name, with the remainder going to `**extra`. Lastly, unfilled parameters
+    /// take their default values.
+    ///
+    /// # Return values
+    /// Ok(frame): Every parameter has a value.
+    /// Err(e): Refer to BindError for the error kinds.
+    pub fn bind<'a>(&self, positional: Arguments<'a>, keywords: KeywordArguments<'a>)
+    -> Result<BoundArguments<'a>, BindError>
+    {
+        if positional.len() > self.positional.len() && self.rest.is_none()
+        {
+            return Err(BindError::TooManyPositional
+            {
+                expected: self.positional.len(),
+                found: positional.len()
+            });
+        }
+        
+        let mut slots : Vec<Option<Argument<'a>>> =
+        self.positional
+            .iter()
+            .chain(&self.keyword_only)
+            .map(|_| None)
+            .collect();
+        
+        let mut positional = positional.into_iter();
+        
+        for (slot, arg) in slots.iter_mut().zip(positional.by_ref().take(self.positional.len()))
+        {
+            *slot = Some(arg);
+        }
+        
+        // The remainder was already checked to fit, as it comes from an Arguments.
+        let rest = Arguments::from_args(positional.collect()).ok().unwrap();
+        
+        let mut extra = KeywordArguments::new();
+        
+        for (name, arg) in keywords
+        {
+            match self.names().position(|n| *n == *name)
+            {
+                Some(idx) if slots[idx].is_some() =>
+                return Err(BindError::MultipleValues { name }),
+                Some(idx) => slots[idx] = Some(arg),
+                None if self.extra.is_some() => { extra.insert_bounded(name, arg); },
+                None => return Err(BindError::UnexpectedKeyword { name })
+            }
+        }
+        
+        let mut params = Vec::with_capacity(slots.len());
+        let mut missing = Vec::new();
+        
+        for (slot, param) in slots.into_iter().zip(self.positional.iter().chain(&self.keyword_only))
+        {
+            match slot.or_else(|| param.default.clone())
+            {
+                Some(arg) => params.push((param.name.clone(), arg)),
+                None => missing.push(param.name.clone())
+            }
+        }
+        
+        if !missing.is_empty()
+        {
+            return Err(BindError::MissingRequired { names: missing });
+        }
+        
+        Ok(BoundArguments { params, rest, extra })
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    format,
+    string::String
+};
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+use crate::MAX_ARG_COUNT;
+
+/// An error raised when a parameter cannot be added to a CommandSpec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommandSpecError
+{
+    /// The name is already used by another parameter, or is reserved for help.
+    /// Flags are named along with their dashes, as in `--long` or `-s`.
+    DuplicateName { name: Box<str> },
+    /// A positional parameter follows the variadic parameter.
+    PositionalAfterVariadic { name: Box<str> },
+    /// The spec already holds MAX_ARG_COUNT flags.
+    TooManyFlags { name: Box<str> }
+}
+
+impl fmt::Display for CommandSpecError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::DuplicateName { name } =>
+            write!(f, "duplicate name '{name}'"),
+            Self::PositionalAfterVariadic { name } =>
+            write!(f, "positional parameter '{name}' follows the variadic parameter"),
+            Self::TooManyFlags { name } =>
+            write!(f, "flag '{name}' exceeds the limit of {MAX_ARG_COUNT} flags")
+        }
+    }
+}
+
+impl error::Error for CommandSpecError {}
+
+/// The kind of a CliError.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CliErrorKind
+{
+    /// `--help` or `-h` was given. The usage text is attached.
+    HelpRequested { help: Box<str> },
+    /// The flag is not declared.
+    UnknownFlag { flag: Box<str> },
+    /// The flag takes a value, but none was given.
+    MissingValue { flag: Box<str> },
+    /// The flag is a switch, but a value was attached to it.
+    UnexpectedValue { flag: Box<str> },
+    /// The value could not be parsed into the declared type.
+    InvalidValue { name: Box<str>, expected: &'static str },
+    /// A required positional parameter did not receive a value.
+    MissingPositional { name: Box<str> },
+    /// There are more positional values than positional parameters.
+    UnexpectedPositional,
+    /// There are more positional values than MAX_ARG_COUNT.
+    TooManyPositionals
+}
+
+/// An error raised while parsing a command line.
+///
+/// Errors caused by a specific token carry its index and text, which
+/// CliError::render uses to point at the token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CliError
+{
+    /// What went wrong.
+    pub kind: CliErrorKind,
+    /// The index of the offending token within the parsed slice.
+    pub index: Option<usize>,
+    /// The offending token.
+    pub token: Option<Box<str>>
+}
+
+impl CliError
+{
+    #[inline(always)]
+    pub(crate) fn new(kind: CliErrorKind) -> Self
+    {
+        Self
+        {
+            kind,
+            index: None,
+            token: None
+        }
+    }
+    
+    #[inline(always)]
+    pub(crate) fn at(kind: CliErrorKind, index: usize, token: &str) -> Self
+    {
+        Self
+        {
+            kind,
+            index: Some(index),
+            token: Some(token.into())
+        }
+    }
+    
+    /// Renders the error, followed by the command line with the offending token underlined.
+    ///
+    /// tokens must be the slice that was parsed. Errors without a token only render the message.
+    pub fn render<S>(&self, tokens: &[S]) -> String
+    where
+        S: AsRef<str>
+    {
+        let mut output = format!("error: {self}");
+        
+        let Some(index) = self.index.filter(|i| *i < tokens.len())
+        else
+        {
+            return output;
+        };
+        
+        let mut line = String::new();
+        let mut marker = String::new();
+        
+        for (idx, token) in tokens.iter().enumerate()
+        {
+            let token = token.as_ref();
+            
+            if idx != 0
+            {
+                line.push(' ');
+                marker.push(' ');
+            }
+            
+            line.push_str(token);
+            
+            let fill = if idx == index { '^' } else { ' ' };
+            
+            marker.extend(core::iter::repeat_n(fill, token.chars().count().max(1)));
+        }
+        
+        output.push_str("\n  ");
+        output.push_str(&line);
+        output.push_str("\n  ");
+        output.push_str(marker.trim_end());
+        
+        output
+    }
+}
+
+impl fmt::Display for CliError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match &self.kind
+        {
+            CliErrorKind::HelpRequested { help } => return f.write_str(help),
+            CliErrorKind::UnknownFlag { flag } =>
+            write!(f, "unknown flag '{flag}'")?,
+            CliErrorKind::MissingValue { flag } =>
+            write!(f, "flag '{flag}' requires a value")?,
+            CliErrorKind::UnexpectedValue { flag } =>
+            write!(f, "flag '{flag}' does not take a value")?,
+            CliErrorKind::InvalidValue { name, expected } =>
+            write!(f, "invalid value for {name}: expected {expected}")?,
+            CliErrorKind::MissingPositional { name } =>
+            write!(f, "missing required argument <{name}>")?,
+            CliErrorKind::UnexpectedPositional =>
+            f.write_str("unexpected argument")?,
+            CliErrorKind::TooManyPositionals =>
+            f.write_str("too many arguments")?
+        }
+        
+        match (&self.token, self.index)
+        {
+            (Some(token), Some(index)) => write!(f, " (token {index}: '{token}')"),
+            _ => Ok(())
+        }
+    }
+}
+
+impl error::Error for CliError {}
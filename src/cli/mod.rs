@@ -0,0 +1,7 @@
+mod error;
+mod parse;
+mod spec;
+
+pub use error::{CliError, CliErrorKind, CommandSpecError};
+pub use parse::ParsedCommand;
+pub use spec::CommandSpec;
@@ -0,0 +1,271 @@
+#[cfg(no_std)]
+use alloc::{
+    format,
+    vec::Vec
+};
+
+#[cfg(not(no_std))]
+use std::env;
+
+use crate::{schema::ValueType, Argument, Arguments, KeywordArguments};
+
+use super::{
+    spec::Flag,
+    CliError,
+    CliErrorKind,
+    CommandSpec
+};
+
+/// The result of parsing a command line.
+#[derive(Clone, Debug)]
+pub struct ParsedCommand
+{
+    /// The positional values, in order, followed by the variadic values.
+    pub positional: Arguments<'static>,
+    /// The flags, keyed by their long names. Switches and flags with
+    /// defaults are always present.
+    pub flags: KeywordArguments<'static>
+}
+
+/// Parses a value into its declared type.
+#[inline(always)]
+fn parse_value(value: &ValueType, name: &str, text: &str, index: usize, token: &str)
+-> Result<Argument<'static>, CliError>
+{
+    match (value.parse)(text)
+    {
+        Some(owned) => Ok(Argument::from(owned)),
+        None => Err(CliError::at(CliErrorKind::InvalidValue { name: name.into(), expected: value.type_name },
+                                 index,
+                                 token))
+    }
+}
+
+/// Walks through the tokens, keeping track of the current index.
+struct Cursor<'t, S>
+{
+    tokens: &'t [S],
+    index: usize
+}
+
+impl<'t, S> Cursor<'t, S>
+where
+    S: AsRef<str>
+{
+    #[inline(always)]
+    fn next(&mut self) -> Option<(usize, &'t str)>
+    {
+        let token = self.tokens.get(self.index)?.as_ref();
+        
+        self.index += 1;
+        
+        Some((self.index - 1, token))
+    }
+}
+
+impl CommandSpec
+{
+    /// Finds a flag by its long name.
+    #[inline(always)]
+    fn long_flag(&self, long: &str) -> Option<&Flag>
+    {
+        self.flags.iter().find(|f| *f.long == *long)
+    }
+    
+    /// Finds a flag by its short name.
+    #[inline(always)]
+    fn short_flag(&self, short: char) -> Option<&Flag>
+    {
+        self.flags.iter().find(|f| f.short == Some(short))
+    }
+    
+    /// Checks if a token is a flag, rather than a positional value.
+    ///
+    /// A lone `-` and negative numbers, such as `-5`, are positional
+    /// unless a short flag of the same name exists.
+    #[inline(always)]
+    fn is_flag(&self, token: &str) -> bool
+    {
+        let mut chars = token.chars();
+        
+        match (chars.next(), chars.next())
+        {
+            (Some('-'), Some(c)) if c.is_ascii_digit() || c == '.' => self.short_flag(c).is_some(),
+            (Some('-'), Some(_)) => true,
+            _ => false
+        }
+    }
+    
+    /// Parses a command line, which must not include the program name.
+    ///
+    /// # Return values
+    /// Ok(parsed): Every token was parsed.
+    /// Err(e): The command line is malformed, or help was requested. Refer to CliErrorKind.
+    pub fn parse<S>(&self, tokens: &[S]) -> Result<ParsedCommand, CliError>
+    where
+        S: AsRef<str>
+    {
+        let mut positional = Vec::new();
+        let mut flags = KeywordArguments::new();
+        
+        let mut cursor = Cursor { tokens, index: 0 };
+        let mut only_positional = false;
+        
+        while let Some((index, token)) = cursor.next()
+        {
+            if only_positional || !self.is_flag(token)
+            {
+                positional.push((index, token));
+            }
+            else if token == "--"
+            {
+                only_positional = true;
+            }
+            else if let Some(long) = token.strip_prefix("--")
+            {
+                let (long, attached) =
+                match long.split_once('=')
+                {
+                    Some((l, v)) => (l, Some(v)),
+                    None => (long, None)
+                };
+                
+                let name = &token[..2 + long.len()];
+                
+                if long == "help"
+                {
+                    return Err(CliError::new(CliErrorKind::HelpRequested { help: self.help().into() }));
+                }
+                
+                let flag =
+                self.long_flag(long)
+                    .ok_or_else(|| CliError::at(CliErrorKind::UnknownFlag { flag: token.into() }, index, token))?;
+                
+                let arg =
+                match (&flag.value, attached)
+                {
+                    (None, None) => Argument::new_owned(true),
+                    (None, Some(_)) =>
+                    return Err(CliError::at(CliErrorKind::UnexpectedValue { flag: name.into() }, index, token)),
+                    (Some(value), Some(text)) => parse_value(value, name, text, index, token)?,
+                    (Some(value), None) =>
+                    {
+                        let (value_index, text) =
+                        cursor.next()
+                              .ok_or_else(|| CliError::at(CliErrorKind::MissingValue { flag: name.into() },
+                                                          index,
+                                                          token))?;
+                        
+                        parse_value(value, name, text, value_index, text)?
+                    }
+                };
+                
+                flags.insert_bounded(&*flag.long, arg);
+            }
+            else
+            {
+                for (offset, short) in token[1..].char_indices()
+                {
+                    if short == 'h'
+                    {
+                        return Err(CliError::new(CliErrorKind::HelpRequested { help: self.help().into() }));
+                    }
+                    
+                    let flag =
+                    self.short_flag(short)
+                        .ok_or_else(|| CliError::at(CliErrorKind::UnknownFlag { flag: format!("-{short}").into() },
+                                                    index,
+                                                    token))?;
+                    
+                    let name = format!("-{short}");
+                    
+                    let Some(value) = &flag.value
+                    else
+                    {
+                        flags.insert_bounded(&*flag.long, Argument::new_owned(true));
+                        continue;
+                    };
+                    
+                    // The rest of the token is the value, or else the next token.
+                    let attached = &token[1 + offset + short.len_utf8()..];
+                    
+                    let arg =
+                    if !attached.is_empty()
+                    {
+                        parse_value(value, &name, attached, index, token)?
+                    }
+                    else
+                    {
+                        let (value_index, text) =
+                        cursor.next()
+                              .ok_or_else(|| CliError::at(CliErrorKind::MissingValue { flag: name.as_str().into() },
+                                                          index,
+                                                          token))?;
+                        
+                        parse_value(value, &name, text, value_index, text)?
+                    };
+                    
+                    flags.insert_bounded(&*flag.long, arg);
+                    
+                    break;
+                }
+            }
+        }
+        
+        for flag in &self.flags
+        {
+            if let Some((default, _)) = &flag.default
+            && !flags.contains_key(&flag.long)
+            {
+                flags.insert_bounded(&*flag.long, default.clone());
+            }
+        }
+        
+        let mut values = Vec::with_capacity(positional.len());
+        let mut given = positional.into_iter();
+        
+        for param in &self.positionals
+        {
+            let (index, token) =
+            given.next()
+                 .ok_or_else(|| CliError::new(CliErrorKind::MissingPositional { name: param.name.clone() }))?;
+            
+            values.push(parse_value(&param.value, &param.name, token, index, token)?);
+        }
+        
+        for (index, token) in given
+        {
+            let Some(variadic) = &self.variadic
+            else
+            {
+                return Err(CliError::at(CliErrorKind::UnexpectedPositional, index, token));
+            };
+            
+            values.push(parse_value(&variadic.value, &variadic.name, token, index, token)?);
+        }
+        
+        let positional =
+        Arguments::from_args(values)
+            .map_err(|_| CliError::new(CliErrorKind::TooManyPositionals))?;
+        
+        Ok(ParsedCommand { positional, flags })
+    }
+    
+    /// Parses the command line of the current process, skipping the program name.
+    ///
+    /// Arguments which are not valid unicode are converted lossily.
+    ///
+    /// # Return values
+    /// Refer to CommandSpec::parse.
+    #[cfg(not(no_std))]
+    pub fn parse_env(&self) -> Result<ParsedCommand, CliError>
+    {
+        let tokens : Vec<String> =
+        env::args_os()
+            .skip(1)
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        
+        self.parse(&tokens)
+    }
+}
@@ -0,0 +1,365 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{
+    any::Any,
+    fmt::{Display, Write},
+    str::FromStr
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    fmt::{Display, Write},
+    str::FromStr
+};
+
+use crate::{schema::ValueType, Argument, TokenParser, MAX_ARG_COUNT};
+
+use super::CommandSpecError;
+
+/// A positional parameter.
+#[derive(Clone, Debug)]
+pub(super) struct Positional
+{
+    pub name: Box<str>,
+    pub help: Box<str>,
+    pub value: ValueType
+}
+
+/// A flag parameter.
+#[derive(Clone, Debug)]
+pub(super) struct Flag
+{
+    pub long: Box<str>,
+    pub short: Option<char>,
+    pub help: Box<str>,
+    /// The value taken by the flag, or None for switches.
+    pub value: Option<ValueType>,
+    /// The name of the value, as shown in the help text.
+    pub value_name: Box<str>,
+    /// The default value, along with its description for the help text.
+    pub default: Option<(Argument<'static>, Box<str>)>
+}
+
+/// The default value name of a flag: its long name in uppercase, with dashes
+/// replaced by underscores.
+#[inline(always)]
+fn default_value_name(long: &str) -> Box<str>
+{
+    long.to_uppercase()
+        .replace('-', "_")
+        .into()
+}
+
+/// A command line specification.
+///
+/// A command takes positional parameters in order, optionally followed by a
+/// variadic parameter that collects every remaining positional value. Flags are
+/// given as `--long value`, `--long=value`, `-s value` or `-svalue`, and switches
+/// may be grouped, as in `-abc`. Every token after `--` is positional.
+///
+/// Each value is parsed into its declared type, producing owned arguments.
+/// `--help` and `-h` are reserved for the generated help text.
+#[derive(Clone, Debug)]
+pub struct CommandSpec
+{
+    pub(super) name: Box<str>,
+    pub(super) about: Box<str>,
+    pub(super) positionals: Vec<Positional>,
+    pub(super) variadic: Option<Positional>,
+    pub(super) flags: Vec<Flag>
+}
+
+impl CommandSpec
+{
+    /// Creates a command without any parameters.
+    #[inline(always)]
+    pub fn new(name: &str) -> Self
+    {
+        Self
+        {
+            name: name.into(),
+            about: "".into(),
+            positionals: Vec::new(),
+            variadic: None,
+            flags: Vec::new()
+        }
+    }
+    
+    /// Sets the description shown at the top of the help text.
+    #[inline(always)]
+    pub fn about(mut self, about: &str) -> Self
+    {
+        self.about = about.into();
+        self
+    }
+    
+    /// Returns the name of the command.
+    #[inline(always)]
+    pub fn name(&self) -> &str
+    {
+        &self.name
+    }
+    
+    /// Checks if a positional name is already in use.
+    #[inline(always)]
+    fn contains_positional(&self, name: &str) -> bool
+    {
+        self.positionals.iter().chain(&self.variadic).any(|p| *p.name == *name)
+    }
+    
+    /// Adds a required positional parameter with a custom parser.
+    ///
+    /// # Return values
+    /// Ok(Self): The parameter was added.
+    /// Err(CommandSpecError::DuplicateName): Another positional parameter has the same name.
+    /// Err(CommandSpecError::PositionalAfterVariadic): The variadic parameter is already set.
+    pub fn positional_with(mut self, name: &str, help: &str, type_name: &'static str, parse: TokenParser)
+    -> Result<Self, CommandSpecError>
+    {
+        if self.contains_positional(name)
+        {
+            return Err(CommandSpecError::DuplicateName { name: name.into() });
+        }
+        
+        if self.variadic.is_some()
+        {
+            return Err(CommandSpecError::PositionalAfterVariadic { name: name.into() });
+        }
+        
+        self.positionals.push(Positional
+        {
+            name: name.into(),
+            help: help.into(),
+            value: ValueType { type_name, parse }
+        });
+        
+        Ok(self)
+    }
+    
+    /// Adds a required positional parameter of type T, parsed through FromStr.
+    ///
+    /// # Return values
+    /// Refer to CommandSpec::positional_with.
+    #[inline(always)]
+    pub fn positional<T>(self, name: &str, help: &str) -> Result<Self, CommandSpecError>
+    where
+        T: FromStr + Any + Clone
+    {
+        let value = ValueType::of::<T>();
+        
+        self.positional_with(name, help, value.type_name, value.parse)
+    }
+    
+    /// Sets the variadic parameter, which collects every remaining positional value as T.
+    ///
+    /// # Return values
+    /// Ok(Self): The parameter was set, replacing any previous one.
+    /// Err(CommandSpecError::DuplicateName): A positional parameter has the same name.
+    pub fn variadic<T>(mut self, name: &str, help: &str) -> Result<Self, CommandSpecError>
+    where
+        T: FromStr + Any + Clone
+    {
+        self.variadic = None;
+        
+        if self.contains_positional(name)
+        {
+            return Err(CommandSpecError::DuplicateName { name: name.into() });
+        }
+        
+        self.variadic = Some(Positional
+        {
+            name: name.into(),
+            help: help.into(),
+            value: ValueType::of::<T>()
+        });
+        
+        Ok(self)
+    }
+    
+    /// Adds a flag, checking its names against the existing ones.
+    fn push_flag(mut self, flag: Flag) -> Result<Self, CommandSpecError>
+    {
+        if &*flag.long == "help"
+        || self.flags.iter().any(|f| f.long == flag.long)
+        {
+            return Err(CommandSpecError::DuplicateName { name: format!("--{}", flag.long).into() });
+        }
+        
+        if let Some(short) = flag.short
+        && (short == 'h' || short == '-' || self.flags.iter().any(|f| f.short == Some(short)))
+        {
+            return Err(CommandSpecError::DuplicateName { name: format!("-{short}").into() });
+        }
+        
+        if self.flags.len() >= MAX_ARG_COUNT
+        {
+            return Err(CommandSpecError::TooManyFlags { name: format!("--{}", flag.long).into() });
+        }
+        
+        self.flags.push(flag);
+        
+        Ok(self)
+    }
+    
+    /// Adds a switch, which is stored as true when given and false otherwise.
+    ///
+    /// # Return values
+    /// Ok(Self): The switch was added.
+    /// Err(CommandSpecError::DuplicateName): Either name is already used, or is reserved for help.
+    /// Err(CommandSpecError::TooManyFlags): The spec already holds MAX_ARG_COUNT flags.
+    #[inline(always)]
+    pub fn switch(self, long: &str, short: Option<char>, help: &str) -> Result<Self, CommandSpecError>
+    {
+        self.push_flag(Flag
+        {
+            long: long.into(),
+            short,
+            help: help.into(),
+            value: None,
+            value_name: "".into(),
+            default: Some((Argument::new_owned(false), "false".into()))
+        })
+    }
+    
+    /// Adds a flag taking a value of type T. The flag is left out when not given.
+    ///
+    /// # Return values
+    /// Refer to CommandSpec::switch.
+    #[inline(always)]
+    pub fn flag<T>(self, long: &str, short: Option<char>, help: &str) -> Result<Self, CommandSpecError>
+    where
+        T: FromStr + Any + Clone
+    {
+        self.push_flag(Flag
+        {
+            long: long.into(),
+            short,
+            help: help.into(),
+            value: Some(ValueType::of::<T>()),
+            value_name: default_value_name(long),
+            default: None
+        })
+    }
+    
+    /// Adds a flag taking a value of type T, which is stored as default when not given.
+    ///
+    /// # Return values
+    /// Refer to CommandSpec::switch.
+    #[inline(always)]
+    pub fn flag_default<T>(self, long: &str, short: Option<char>, help: &str, default: T)
+    -> Result<Self, CommandSpecError>
+    where
+        T: FromStr + Display + Any + Clone
+    {
+        let description = default.to_string().into();
+        
+        self.push_flag(Flag
+        {
+            long: long.into(),
+            short,
+            help: help.into(),
+            value: Some(ValueType::of::<T>()),
+            value_name: default_value_name(long),
+            default: Some((Argument::new_owned(default), description))
+        })
+    }
+    
+    /// Sets the name of the value taken by the most recently added flag, as
+    /// shown in the help text.
+    ///
+    /// By default, this is the flag's long name in uppercase, as in `--speed <SPEED>`.
+    /// Without any flags, or if the last flag is a switch, this does nothing.
+    #[inline(always)]
+    pub fn value_name(mut self, name: &str) -> Self
+    {
+        if let Some(flag) = self.flags.last_mut().filter(|f| f.value.is_some())
+        {
+            flag.value_name = name.into();
+        }
+        
+        self
+    }
+    
+    /// Generates the one-line usage, as in `usage: name [OPTIONS] <a> [rest]...`.
+    pub fn usage(&self) -> String
+    {
+        let mut output = format!("usage: {}", self.name);
+        
+        if !self.flags.is_empty()
+        {
+            output.push_str(" [OPTIONS]");
+        }
+        
+        for positional in &self.positionals
+        {
+            let _ = write!(output, " <{}>", positional.name);
+        }
+        
+        if let Some(variadic) = &self.variadic
+        {
+            let _ = write!(output, " [{}]...", variadic.name);
+        }
+        
+        output
+    }
+    
+    /// Generates the help text, listing every parameter along with its help.
+    pub fn help(&self) -> String
+    {
+        let mut output = String::new();
+        
+        // Writing into a String does not fail.
+        if !self.about.is_empty()
+        {
+            let _ = writeln!(output, "{}\n", self.about);
+        }
+        
+        output.push_str(&self.usage());
+        
+        if !self.positionals.is_empty() || self.variadic.is_some()
+        {
+            output.push_str("\n\narguments:");
+            
+            for positional in self.positionals.iter().chain(&self.variadic)
+            {
+                let _ = write!(output, "\n  <{}>  {}", positional.name, positional.help);
+            }
+        }
+        
+        output.push_str("\n\noptions:");
+        
+        for flag in &self.flags
+        {
+            let mut names =
+            match flag.short
+            {
+                Some(short) => format!("-{short}, --{}", flag.long),
+                None => format!("    --{}", flag.long)
+            };
+            
+            if flag.value.is_some()
+            {
+                let _ = write!(names, " <{}>", flag.value_name);
+            }
+            
+            let _ = write!(output, "\n  {names}  {}", flag.help);
+            
+            if let (Some(_), Some((_, default))) = (&flag.value, &flag.default)
+            {
+                let _ = write!(output, " [default: {default}]");
+            }
+        }
+        
+        output.push_str("\n  -h, --help  Prints this help text");
+        
+        output
+    }
+}
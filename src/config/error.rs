@@ -0,0 +1,79 @@
+#[cfg(no_std)]
+use alloc::boxed::Box;
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt, io};
+
+use crate::SchemaError;
+
+use super::ValueSource;
+
+/// The kind of a ConfigError.
+#[derive(Debug)]
+pub enum ConfigErrorKind
+{
+    /// The line is neither a section, a `key = value` pair, a comment nor blank.
+    Syntax,
+    /// The key is unknown, or its value is invalid.
+    Schema(SchemaError),
+    /// The value does not fit, as the configuration already holds MAX_ARG_COUNT values.
+    Capacity,
+    /// The file could not be read.
+    #[cfg(not(no_std))]
+    Io(io::Error)
+}
+
+/// An error raised while loading configuration values.
+#[derive(Debug)]
+pub struct ConfigError
+{
+    /// Where the offending value came from.
+    pub source: ValueSource,
+    /// What went wrong.
+    pub kind: ConfigErrorKind
+}
+
+impl ConfigError
+{
+    #[inline(always)]
+    pub(super) fn syntax(name: &str, line: usize) -> Self
+    {
+        Self
+        {
+            source: ValueSource::File { name: Box::from(name), line },
+            kind: ConfigErrorKind::Syntax
+        }
+    }
+}
+
+impl fmt::Display for ConfigError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match &self.kind
+        {
+            ConfigErrorKind::Syntax => write!(f, "{}: malformed line", self.source),
+            ConfigErrorKind::Capacity => write!(f, "{}: too many values", self.source),
+            ConfigErrorKind::Schema(e) => write!(f, "{}: {e}", self.source),
+            #[cfg(not(no_std))]
+            ConfigErrorKind::Io(e) => write!(f, "{}: {e}", self.source)
+        }
+    }
+}
+
+impl error::Error for ConfigError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match &self.kind
+        {
+            ConfigErrorKind::Syntax | ConfigErrorKind::Capacity => None,
+            ConfigErrorKind::Schema(e) => Some(e),
+            #[cfg(not(no_std))]
+            ConfigErrorKind::Io(e) => Some(e)
+        }
+    }
+}
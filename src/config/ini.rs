@@ -0,0 +1,74 @@
+#[cfg(no_std)]
+use alloc::{format, string::String};
+
+use super::ConfigError;
+
+/// A `key = value` pair read from a configuration file.
+pub(super) struct Entry<'t>
+{
+    pub key: String,
+    pub value: &'t str,
+    pub line: usize
+}
+
+/// Reads the entries of an INI-style file.
+///
+/// Lines starting with `#` or `;` are comments. `[section]` headers prefix the keys
+/// that follow with `section.`. Values surrounded by double quotes have the quotes
+/// removed, which keeps leading and trailing whitespace. Line numbers start at 1.
+pub(super) fn entries<'t>(text: &'t str, name: &str)
+-> impl Iterator<Item = Result<Entry<'t>, ConfigError>>
+{
+    let mut section = String::new();
+    
+    text.lines()
+        .enumerate()
+        .filter_map(move |(idx, line)|
+        {
+            let line_number = idx + 1;
+            let line = line.trim();
+            
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';')
+            {
+                return None;
+            }
+            
+            if let Some(header) = line.strip_prefix('[')
+            {
+                return match header.strip_suffix(']')
+                {
+                    Some(header) =>
+                    {
+                        section = String::from(header.trim());
+                        None
+                    }
+                    None => Some(Err(ConfigError::syntax(name, line_number)))
+                };
+            }
+            
+            let Some((key, value)) = line.split_once('=')
+            else
+            {
+                return Some(Err(ConfigError::syntax(name, line_number)));
+            };
+            
+            let key = key.trim();
+            let value = value.trim();
+            
+            if key.is_empty()
+            {
+                return Some(Err(ConfigError::syntax(name, line_number)));
+            }
+            
+            let value =
+            value.strip_prefix('"')
+                 .and_then(|v| v.strip_suffix('"'))
+                 .unwrap_or(value);
+            
+            let key =
+            if section.is_empty() { String::from(key) }
+            else { format!("{section}.{key}") };
+            
+            Some(Ok(Entry { key, value, line: line_number }))
+        })
+}
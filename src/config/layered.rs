@@ -0,0 +1,289 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::any::Any;
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    env,
+    ffi::OsString,
+    fs,
+    path::Path
+};
+
+use crate::{Argument, KeywordArguments, Schema};
+
+use super::{ini, ConfigError, ConfigErrorKind, ValueSource};
+
+/// A set of configuration values, loaded in layers through a Schema.
+///
+/// Values come from four layers which, from lowest to highest precedence, are:
+/// defaults, configuration files, environment variables and explicit overrides.
+/// A value only replaces another if its layer has the same or a higher precedence,
+/// so the layers can be loaded in any order. The source of each value is kept,
+/// and can be queried through Config::source.
+#[derive(Clone, Debug)]
+pub struct Config
+{
+    schema: Schema,
+    values: KeywordArguments<'static>,
+    /// The source of each value, in the same order as values.
+    sources: Vec<ValueSource>
+}
+
+impl Config
+{
+    /// Creates an empty configuration, whose values are parsed through schema.
+    #[inline(always)]
+    pub fn new(schema: Schema) -> Self
+    {
+        Self
+        {
+            schema,
+            values: KeywordArguments::new(),
+            sources: Vec::new()
+        }
+    }
+    
+    /// Returns the schema.
+    #[inline(always)]
+    pub fn schema(&self) -> &Schema
+    {
+        &self.schema
+    }
+    
+    /// Stores a value, unless the current value comes from a higher layer.
+    ///
+    /// # Return values
+    /// Ok(()): The value was stored, or a higher layer already holds one.
+    /// Err(e): The key is new, and the configuration already holds MAX_ARG_COUNT values.
+    fn set(&mut self, key: &str, arg: Argument<'static>, source: ValueSource) -> Result<(), ConfigError>
+    {
+        let position = self.values.keys().position(|k| k == key);
+        
+        match position
+        {
+            Some(idx) if self.sources[idx].rank() > source.rank() => (),
+            Some(idx) =>
+            {
+                if let Some(value) = self.values.get_mut(key)
+                {
+                    *value = arg;
+                }
+                
+                self.sources[idx] = source;
+            }
+            None =>
+            {
+                if self.values.insert(key, arg).is_err()
+                {
+                    return Err(ConfigError { source, kind: ConfigErrorKind::Capacity });
+                }
+                
+                self.sources.push(source);
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks a value against the schema before storing it.
+    ///
+    /// # Return values
+    /// Refer to Config::set_default.
+    fn set_checked<T>(&mut self, key: &str, value: T, source: ValueSource) -> Result<(), ConfigError>
+    where
+        T: Any + Clone
+    {
+        match self.schema.check::<T>(key)
+        {
+            Ok(()) => self.set(key, Argument::new_owned(value), source),
+            Err(e) => Err(ConfigError { source, kind: ConfigErrorKind::Schema(e) })
+        }
+    }
+    
+    /// Sets a default value, which every other layer replaces.
+    ///
+    /// # Return values
+    /// Ok(()): The value was set, or a higher layer already holds one.
+    /// Err(e): The key is not declared by the schema, its declared type is not T
+    /// as checked by Schema::check, or the configuration is full.
+    #[inline(always)]
+    pub fn set_default<T>(&mut self, key: &str, value: T) -> Result<(), ConfigError>
+    where
+        T: Any + Clone
+    {
+        self.set_checked(key, value, ValueSource::Default)
+    }
+    
+    /// Sets an explicit override, which replaces every other layer.
+    ///
+    /// # Return values
+    /// Refer to Config::set_default.
+    #[inline(always)]
+    pub fn set_override<T>(&mut self, key: &str, value: T) -> Result<(), ConfigError>
+    where
+        T: Any + Clone
+    {
+        self.set_checked(key, value, ValueSource::Override)
+    }
+    
+    /// Loads the values of an INI-style configuration.
+    ///
+    /// Lines are either blank, comments starting with `#` or `;`, `[section]` headers
+    /// or `key = value` pairs. Keys within a section are prefixed by `section.`.
+    /// Quoted values have their quotes removed. The name is used as the file name
+    /// of each value's source.
+    ///
+    /// # Return values
+    /// Ok(()): Every value was loaded.
+    /// Err(e): A line is malformed, a key is unknown or its value invalid,
+    /// or the configuration is full.
+    /// Values before the offending line are kept.
+    pub fn load_str(&mut self, text: &str, name: &str) -> Result<(), ConfigError>
+    {
+        for entry in ini::entries(text, name)
+        {
+            let entry = entry?;
+            
+            let source = ValueSource::File { name: name.into(), line: entry.line };
+            
+            match self.schema.parse(&entry.key, entry.value)
+            {
+                Ok(arg) => self.set(&entry.key, arg, source)?,
+                Err(e) => return Err(ConfigError { source, kind: ConfigErrorKind::Schema(e) })
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Loads the values of an INI-style configuration file.
+    ///
+    /// # Return values
+    /// Refer to Config::load_str. Failing to read the file is reported as ConfigErrorKind::Io.
+    #[cfg(not(no_std))]
+    pub fn load_file<P>(&mut self, path: P) -> Result<(), ConfigError>
+    where
+        P: AsRef<Path>
+    {
+        let path = path.as_ref();
+        let name = path.to_string_lossy();
+        
+        let text =
+        fs::read_to_string(path)
+            .map_err(|e| ConfigError
+            {
+                source: ValueSource::File { name: Box::from(&*name), line: 0 },
+                kind: ConfigErrorKind::Io(e)
+            })?;
+        
+        self.load_str(&text, &name)
+    }
+    
+    /// Loads values from a set of environment variables.
+    ///
+    /// Only the variables starting with prefix are read. The prefix is removed, and the
+    /// rest is lowercased, with `__` standing for the `.` of sections. For instance,
+    /// with the prefix `APP_`, `APP_SERVER__PORT` loads the key `server.port`.
+    /// Variables whose keys are not declared by the schema are skipped.
+    ///
+    /// # Return values
+    /// Ok(()): Every matching variable was loaded.
+    /// Err(e): The value of a variable is invalid, or the configuration is full.
+    /// Values before it are kept.
+    pub fn load_vars<I, K, V>(&mut self, prefix: &str, vars: I) -> Result<(), ConfigError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>
+    {
+        for (variable, value) in vars
+        {
+            let variable = variable.as_ref();
+            
+            let Some(key) = variable.strip_prefix(prefix)
+            else { continue };
+            
+            let key : String = key.to_lowercase().replace("__", ".");
+            
+            if !self.schema.contains(&key)
+            {
+                continue;
+            }
+            
+            let source = ValueSource::Env { variable: variable.into() };
+            
+            match self.schema.parse(&key, value.as_ref())
+            {
+                Ok(arg) => self.set(&key, arg, source)?,
+                Err(e) => return Err(ConfigError { source, kind: ConfigErrorKind::Schema(e) })
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Loads values from the environment of the current process.
+    ///
+    /// Variables which are not valid unicode are skipped.
+    ///
+    /// # Return values
+    /// Refer to Config::load_vars.
+    #[cfg(not(no_std))]
+    #[inline(always)]
+    pub fn load_env(&mut self, prefix: &str) -> Result<(), ConfigError>
+    {
+        let vars =
+        env::vars_os()
+            .filter_map(|(k, v): (OsString, OsString)| Some((k.into_string().ok()?, v.into_string().ok()?)));
+        
+        self.load_vars(prefix, vars)
+    }
+    
+    /// Acquires a value.
+    #[inline(always)]
+    pub fn get(&self, key: &str) -> Option<&Argument<'static>>
+    {
+        self.values.get(key)
+    }
+    
+    /// Acquires the source of a value.
+    #[inline(always)]
+    pub fn source(&self, key: &str) -> Option<&ValueSource>
+    {
+        self.values
+            .keys()
+            .position(|k| k == key)
+            .map(|idx| &self.sources[idx])
+    }
+    
+    /// Iterates over each value along with its source.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Argument<'static>, &ValueSource)>
+    {
+        self.values
+            .iter()
+            .zip(&self.sources)
+            .map(|((k, a), s)| (k, a, s))
+    }
+    
+    /// Provides access to the values.
+    #[inline(always)]
+    pub fn values(&self) -> &KeywordArguments<'static>
+    {
+        &self.values
+    }
+    
+    /// Consumes the configuration, returning its values.
+    #[inline(always)]
+    pub fn into_values(self) -> KeywordArguments<'static>
+    {
+        self.values
+    }
+}
@@ -0,0 +1,8 @@
+mod error;
+mod ini;
+mod layered;
+mod source;
+
+pub use error::{ConfigError, ConfigErrorKind};
+pub use layered::Config;
+pub use source::ValueSource;
@@ -0,0 +1,55 @@
+#[cfg(no_std)]
+use alloc::boxed::Box;
+
+#[cfg(no_std)]
+use core::fmt;
+
+#[cfg(not(no_std))]
+use std::fmt;
+
+/// Where a configuration value came from.
+///
+/// The variants are ordered by precedence, from lowest to highest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueSource
+{
+    /// A default value.
+    Default,
+    /// A line of a configuration file. The name is the file's path, or
+    /// the name given when loading from text.
+    File { name: Box<str>, line: usize },
+    /// An environment variable.
+    Env { variable: Box<str> },
+    /// An explicit override.
+    Override
+}
+
+impl ValueSource
+{
+    /// The precedence of the source. Higher values replace lower ones.
+    #[inline(always)]
+    pub(super) fn rank(&self) -> u8
+    {
+        match self
+        {
+            Self::Default => 0,
+            Self::File { .. } => 1,
+            Self::Env { .. } => 2,
+            Self::Override => 3
+        }
+    }
+}
+
+impl fmt::Display for ValueSource
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Default => f.write_str("default"),
+            Self::File { name, line } => write!(f, "{name}:{line}"),
+            Self::Env { variable } => write!(f, "environment variable {variable}"),
+            Self::Override => f.write_str("override")
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod registry;
+
+pub use registry::{Conversion, ConversionRegistry, Converted, Converter};
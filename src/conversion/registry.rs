@@ -0,0 +1,303 @@
+#[cfg(no_std)]
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{
+    any::{self, Any, TypeId},
+    fmt
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{self, Any, TypeId},
+    collections::{BTreeMap, BTreeSet},
+    fmt
+};
+
+use crate::OwnedArgument;
+
+/// A function converting a value into an owned argument of another type.
+///
+/// Returns None if the value cannot be converted, such as a string failing to parse.
+pub type Converter = fn(&dyn Any) -> Option<OwnedArgument>;
+
+/// A registered conversion between two types.
+#[derive(Clone, Copy)]
+pub struct Conversion
+{
+    from: TypeId,
+    to: TypeId,
+    from_name: &'static str,
+    to_name: &'static str,
+    converter: Converter
+}
+
+impl fmt::Debug for Conversion
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{} -> {}", self.from_name, self.to_name)
+    }
+}
+
+impl Conversion
+{
+    /// The TypeId of the source type.
+    #[inline(always)]
+    pub fn from(&self) -> TypeId
+    {
+        self.from
+    }
+    
+    /// The TypeId of the target type.
+    #[inline(always)]
+    pub fn to(&self) -> TypeId
+    {
+        self.to
+    }
+    
+    /// The name of the source type.
+    #[inline(always)]
+    pub fn from_name(&self) -> &'static str
+    {
+        self.from_name
+    }
+    
+    /// The name of the target type.
+    #[inline(always)]
+    pub fn to_name(&self) -> &'static str
+    {
+        self.to_name
+    }
+    
+    /// Runs the converter, discarding outputs that are not of the target type.
+    #[inline(always)]
+    fn apply(&self, value: &dyn Any) -> Option<OwnedArgument>
+    {
+        (self.converter)(value)
+            .filter(|o| (**o).type_id() == self.to)
+    }
+}
+
+/// A converted value, along with the conversions that produced it.
+#[derive(Clone, Debug)]
+pub struct Converted<T>
+{
+    /// The converted value.
+    pub value: T,
+    /// Each conversion that was applied, in order.
+    ///
+    /// This is empty if the value already had the requested type.
+    pub steps: Vec<Conversion>
+}
+
+impl<T> Converted<T>
+{
+    /// Checks whether or not the value already had the requested type.
+    #[inline(always)]
+    pub fn is_exact(&self) -> bool
+    {
+        self.steps.is_empty()
+    }
+}
+
+/// A table of user defined conversions between types.
+///
+/// Conversions can be chained: if `A -> B` and `B -> C` are registered,
+/// then an `A` converts into a `C`. The length of a chain is limited by
+/// the registry's max depth.
+#[derive(Clone, Debug)]
+pub struct ConversionRegistry
+{
+    /// Conversions, grouped by their source type.
+    table: BTreeMap<TypeId, Vec<Conversion>>,
+    max_depth: usize
+}
+
+impl Default for ConversionRegistry
+{
+    #[inline(always)]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl ConversionRegistry
+{
+    /// The default limit on the length of a conversion chain.
+    pub const DEFAULT_MAX_DEPTH : usize = 4;
+    
+    /// Creates an empty registry.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self
+        {
+            table: BTreeMap::new(),
+            max_depth: Self::DEFAULT_MAX_DEPTH
+        }
+    }
+    
+    /// Returns the limit on the length of a conversion chain.
+    #[inline(always)]
+    pub fn max_depth(&self) -> usize
+    {
+        self.max_depth
+    }
+    
+    /// Sets the limit on the length of a conversion chain.
+    ///
+    /// A depth of 0 disables conversions, leaving only exact matches.
+    #[inline(always)]
+    pub fn set_max_depth(&mut self, depth: usize)
+    {
+        self.max_depth = depth;
+    }
+    
+    /// Registers a conversion from A to B.
+    ///
+    /// The converter receives a value of type A. Any output that is not of type B
+    /// is treated as a failed conversion. If a conversion from A to B already exists,
+    /// it gets replaced.
+    pub fn register<A, B>(&mut self, converter: Converter)
+    where
+        A: Any,
+        B: Any
+    {
+        let conversion =
+        Conversion
+        {
+            from: TypeId::of::<A>(),
+            to: TypeId::of::<B>(),
+            from_name: any::type_name::<A>(),
+            to_name: any::type_name::<B>(),
+            converter
+        };
+        
+        let entries = self.table.entry(conversion.from).or_default();
+        
+        match entries.iter_mut().find(|c| c.to == conversion.to)
+        {
+            Some(c) => *c = conversion,
+            None => entries.push(conversion)
+        }
+    }
+    
+    /// Checks if there is a direct conversion from A to B.
+    #[inline(always)]
+    pub fn contains<A, B>(&self) -> bool
+    where
+        A: Any,
+        B: Any
+    {
+        self.table
+            .get(&TypeId::of::<A>())
+            .is_some_and(|e| e.iter().any(|c| c.to == TypeId::of::<B>()))
+    }
+    
+    /// Iterates over the registered conversions from a type.
+    #[inline(always)]
+    fn conversions_from(&self, from: TypeId) -> impl Iterator<Item = &Conversion>
+    {
+        self.table
+            .get(&from)
+            .into_iter()
+            .flatten()
+    }
+    
+    /// Converts a value into T.
+    ///
+    /// An exact match is tried first. Otherwise, the registered conversions are
+    /// searched breadth first, which prefers the shortest chain. Conversions are
+    /// applied as the search goes, so a converter returning None only discards
+    /// the chains going through it.
+    ///
+    /// Returns None if no chain of at most max_depth conversions succeeds.
+    pub fn convert<T>(&self, value: &dyn Any) -> Option<Converted<T>>
+    where
+        T: Any + Clone
+    {
+        if let Some(v) = value.downcast_ref::<T>()
+        {
+            return Some(Converted { value: v.clone(), steps: Vec::new() });
+        }
+        
+        if self.max_depth == 0
+        {
+            return None;
+        }
+        
+        let target = TypeId::of::<T>();
+        
+        let mut visited = BTreeSet::from([value.type_id()]);
+        
+        // The first level borrows the source, while later levels own their values.
+        let mut frontier : Vec<(OwnedArgument, Vec<Conversion>)> = Vec::new();
+        
+        for conversion in self.conversions_from(value.type_id())
+        {
+            if let Some(output) = conversion.apply(value)
+            {
+                if conversion.to == target
+                {
+                    return Self::finish(output, [*conversion].into());
+                }
+                
+                if visited.insert(conversion.to)
+                {
+                    frontier.push((output, [*conversion].into()));
+                }
+            }
+        }
+        
+        for _ in 1..self.max_depth
+        {
+            let mut next = Vec::new();
+            
+            for (current, steps) in frontier
+            {
+                for conversion in self.conversions_from((*current).type_id())
+                {
+                    let Some(output) = conversion.apply(&*current)
+                    else { continue };
+                    
+                    let mut steps = steps.clone();
+                    steps.push(*conversion);
+                    
+                    if conversion.to == target
+                    {
+                        return Self::finish(output, steps);
+                    }
+                    
+                    if visited.insert(conversion.to)
+                    {
+                        next.push((output, steps));
+                    }
+                }
+            }
+            
+            if next.is_empty()
+            {
+                break;
+            }
+            
+            frontier = next;
+        }
+        
+        None
+    }
+    
+    #[inline(always)]
+    fn finish<T>(output: OwnedArgument, steps: Vec<Conversion>) -> Option<Converted<T>>
+    where
+        T: Any + Clone
+    {
+        output.downcast_owned::<T>()
+              .ok()
+              .map(|value| Converted { value, steps })
+    }
+}
@@ -0,0 +1,88 @@
+use std::{error, fmt, io};
+
+use crate::{BatchError, SchemaError};
+
+/// The kind of a CsvError.
+#[derive(Debug)]
+pub enum CsvErrorKind
+{
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The input ended within a quoted field.
+    UnclosedQuote,
+    /// A quote appears within an unquoted field, or text follows a closing quote.
+    StrayQuote,
+    /// The record does not have as many fields as there are columns.
+    FieldCount { expected: usize, found: usize },
+    /// A header names a column the schema does not declare.
+    UnknownColumn { name: Box<str> },
+    /// A header names the same column twice.
+    DuplicateColumn { name: Box<str> },
+    /// The header does not name a column declared by the schema.
+    MissingColumn { name: Box<str> },
+    /// The schema declares more columns than MAX_ARG_COUNT.
+    TooManyColumns,
+    /// The field is not a valid value for its column.
+    Schema(SchemaError),
+    /// The argument has no text rendering.
+    Unsupported,
+    /// The rows could not be gathered into an ArgumentsBatch.
+    Batch(BatchError)
+}
+
+/// An error raised while reading or writing CSV.
+///
+/// Rows and columns are numbered from 1, and rows count records rather than
+/// lines, including the header. A record with quoted line breaks is a single row.
+#[derive(Debug)]
+pub struct CsvError
+{
+    /// The row the error happened at.
+    pub row: usize,
+    /// The column the error happened at, if it is about a single field.
+    pub column: Option<usize>,
+    /// What went wrong.
+    pub kind: CsvErrorKind
+}
+
+impl fmt::Display for CsvError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self.column
+        {
+            Some(column) => write!(f, "row {}, column {column}: ", self.row)?,
+            None => write!(f, "row {}: ", self.row)?
+        }
+        
+        match &self.kind
+        {
+            CsvErrorKind::Io(e) => write!(f, "{e}"),
+            CsvErrorKind::UnclosedQuote => f.write_str("unclosed quote"),
+            CsvErrorKind::StrayQuote => f.write_str("misplaced quote"),
+            CsvErrorKind::FieldCount { expected, found } =>
+            write!(f, "found {found} fields, expected {expected}"),
+            CsvErrorKind::UnknownColumn { name } => write!(f, "unknown column '{name}'"),
+            CsvErrorKind::DuplicateColumn { name } => write!(f, "duplicate column '{name}'"),
+            CsvErrorKind::MissingColumn { name } => write!(f, "missing column '{name}'"),
+            CsvErrorKind::TooManyColumns => f.write_str("too many columns"),
+            CsvErrorKind::Schema(e) => write!(f, "{e}"),
+            CsvErrorKind::Unsupported => f.write_str("the argument cannot be rendered as text"),
+            CsvErrorKind::Batch(e) => write!(f, "{e}")
+        }
+    }
+}
+
+impl error::Error for CsvError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match &self.kind
+        {
+            CsvErrorKind::Io(e) => Some(e),
+            CsvErrorKind::Schema(e) => Some(e),
+            CsvErrorKind::Batch(e) => Some(e),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod error;
+mod reader;
+mod writer;
+
+pub use error::{CsvError, CsvErrorKind};
+pub use reader::CsvReader;
+pub use writer::CsvWriter;
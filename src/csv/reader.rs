@@ -0,0 +1,372 @@
+use std::{
+    io::{BufRead, BufReader, Read},
+    mem
+};
+
+use crate::{Argument, Arguments, ArgumentsBatch, BatchError, Schema};
+
+use super::{CsvError, CsvErrorKind};
+
+/// A streaming CSV reader, converting each record into Arguments.
+///
+/// Records follow RFC 4180: fields are separated by commas, records end with
+/// CRLF or LF, and fields may be quoted, with `""` standing for a quote. Quoted
+/// fields may hold commas and line breaks. Blank lines are skipped.
+///
+/// Each field is parsed through the schema of its column, and the arguments of a
+/// row follow the declaration order of the schema. By default, the first record is a
+/// header naming each column, in any order. Without a header, the fields follow the
+/// declaration order of the schema.
+#[derive(Debug)]
+pub struct CsvReader<R>
+{
+    source: BufReader<R>,
+    schema: Schema,
+    /// The name of each column, in declaration order.
+    names: Vec<Box<str>>,
+    /// The declared column of each field, once the header has been read.
+    order: Option<Vec<usize>>,
+    has_header: bool,
+    /// The number of the last record read.
+    row: usize,
+    /// Set once reading has failed, or the input has ended.
+    done: bool,
+    line: String
+}
+
+impl<R> CsvReader<R>
+where
+    R: Read
+{
+    /// Creates a reader over source, whose first record is a header.
+    #[inline(always)]
+    pub fn new(source: R, schema: Schema) -> Self
+    {
+        let names =
+        schema.keys()
+              .map(|(k, _)| Box::from(k))
+              .collect();
+        
+        Self
+        {
+            source: BufReader::new(source),
+            schema,
+            names,
+            order: None,
+            has_header: true,
+            row: 0,
+            done: false,
+            line: String::new()
+        }
+    }
+    
+    /// Reads the records without a header, consuming and returning the reader.
+    ///
+    /// This only has an effect before the first record is read.
+    #[inline(always)]
+    pub fn without_header(mut self) -> Self
+    {
+        self.has_header = false;
+        self
+    }
+    
+    /// Returns the schema.
+    #[inline(always)]
+    pub fn schema(&self) -> &Schema
+    {
+        &self.schema
+    }
+    
+    /// Returns the number of the last record read, counting the header.
+    #[inline(always)]
+    pub fn row(&self) -> usize
+    {
+        self.row
+    }
+    
+    #[inline(always)]
+    fn error(&self, column: Option<usize>, kind: CsvErrorKind) -> CsvError
+    {
+        CsvError { row: self.row, column, kind }
+    }
+    
+    /// Reads the fields of the next non-blank record.
+    ///
+    /// Returns Ok(None) once the input has ended.
+    fn read_record(&mut self) -> Result<Option<Vec<String>>, CsvError>
+    {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut closed = false;
+        let mut started = false;
+        
+        loop
+        {
+            self.line.clear();
+            
+            let read =
+            self.source
+                .read_line(&mut self.line)
+                .map_err(|e| CsvError { row: self.row + 1, column: None, kind: CsvErrorKind::Io(e) })?;
+            
+            if read == 0
+            {
+                if !started
+                {
+                    return Ok(None);
+                }
+                
+                if quoted
+                {
+                    return Err(self.error(Some(fields.len() + 1), CsvErrorKind::UnclosedQuote));
+                }
+                
+                break;
+            }
+            
+            if !started
+            {
+                started = true;
+                self.row += 1;
+            }
+            
+            let mut chars = self.line.chars().peekable();
+            
+            while let Some(c) = chars.next()
+            {
+                if quoted
+                {
+                    match c
+                    {
+                        '"' if chars.peek() == Some(&'"') =>
+                        {
+                            chars.next();
+                            field.push('"');
+                        }
+                        '"' =>
+                        {
+                            quoted = false;
+                            closed = true;
+                        }
+                        c => field.push(c)
+                    }
+                    
+                    continue;
+                }
+                
+                match c
+                {
+                    ',' =>
+                    {
+                        fields.push(mem::take(&mut field));
+                        closed = false;
+                    }
+                    '\r' if chars.peek() == Some(&'\n') => (),
+                    '\n' if fields.is_empty() && field.is_empty() && !closed =>
+                    {
+                        // A blank line, which starts over on the next line.
+                        started = false;
+                        self.row -= 1;
+                    }
+                    '\n' => break,
+                    '"' if field.is_empty() && !closed => quoted = true,
+                    _ if closed => return Err(self.error(Some(fields.len() + 1), CsvErrorKind::StrayQuote)),
+                    '"' => return Err(self.error(Some(fields.len() + 1), CsvErrorKind::StrayQuote)),
+                    c => field.push(c)
+                }
+            }
+            
+            if started && !quoted && self.line.ends_with('\n')
+            {
+                break;
+            }
+        }
+        
+        fields.push(field);
+        
+        Ok(Some(fields))
+    }
+    
+    /// Reads the header, mapping each field to its declared column.
+    ///
+    /// Returns Ok(None) if the input is empty.
+    fn read_header(&mut self) -> Result<Option<Vec<usize>>, CsvError>
+    {
+        if !self.has_header
+        {
+            return Ok(Some((0..self.names.len()).collect()));
+        }
+        
+        let Some(header) = self.read_record()?
+        else { return Ok(None) };
+        
+        let mut order = Vec::with_capacity(header.len());
+        
+        for (column, name) in header.iter().enumerate()
+        {
+            let declared =
+            self.names
+                .iter()
+                .position(|n| **n == **name)
+                .ok_or_else(|| self.error(Some(column + 1), CsvErrorKind::UnknownColumn { name: name.as_str().into() }))?;
+            
+            if order.contains(&declared)
+            {
+                return Err(self.error(Some(column + 1), CsvErrorKind::DuplicateColumn { name: name.as_str().into() }));
+            }
+            
+            order.push(declared);
+        }
+        
+        if let Some(missing) = (0..self.names.len()).find(|idx| !order.contains(idx))
+        {
+            return Err(self.error(None, CsvErrorKind::MissingColumn { name: self.names[missing].clone() }));
+        }
+        
+        Ok(Some(order))
+    }
+    
+    /// Parses the fields of a record into arguments, in declaration order.
+    fn convert(&self, fields: Vec<String>, order: &[usize]) -> Result<Arguments<'static>, CsvError>
+    {
+        if fields.len() != order.len()
+        {
+            return Err(self.error(None, CsvErrorKind::FieldCount { expected: order.len(), found: fields.len() }));
+        }
+        
+        let mut args : Vec<Option<Argument<'static>>> = Vec::new();
+        args.resize_with(order.len(), || None);
+        
+        for (column, (field, &declared)) in fields.iter().zip(order).enumerate()
+        {
+            let arg =
+            self.schema
+                .parse(&self.names[declared], field)
+                .map_err(|e| self.error(Some(column + 1), CsvErrorKind::Schema(e)))?;
+            
+            args[declared] = Some(arg);
+        }
+        
+        // Every declared column is mapped to a single field.
+        Arguments::from_args(args.into_iter().flatten().collect())
+            .map_err(|_| self.error(None, CsvErrorKind::TooManyColumns))
+    }
+    
+    /// Reads the next record as arguments.
+    ///
+    /// # Return values
+    /// Ok(Some(args)): The arguments of the next record.
+    /// Ok(None): The input has ended.
+    /// Err(e): The record, or the header, could not be read or parsed. Reading may
+    /// continue past invalid records, but not past failed reads, unclosed quotes or headers.
+    pub fn read_row(&mut self) -> Result<Option<Arguments<'static>>, CsvError>
+    {
+        if self.done
+        {
+            return Ok(None);
+        }
+        
+        let order =
+        match self.order.take()
+        {
+            Some(order) => order,
+            None => match self.read_header()
+            {
+                Ok(Some(order)) => order,
+                Ok(None) =>
+                {
+                    self.done = true;
+                    return Ok(None);
+                }
+                Err(e) =>
+                {
+                    self.done = true;
+                    return Err(e);
+                }
+            }
+        };
+        
+        let record = self.read_record();
+        
+        let result =
+        match record
+        {
+            Ok(Some(fields)) => self.convert(fields, &order).map(Some),
+            Ok(None) =>
+            {
+                self.done = true;
+                Ok(None)
+            }
+            Err(e) =>
+            {
+                // A misplaced quote only discards the rest of its line.
+                self.done = !matches!(e.kind, CsvErrorKind::StrayQuote);
+                Err(e)
+            }
+        };
+        
+        self.order = Some(order);
+        
+        result
+    }
+    
+    /// Reads up to max_rows records into a columnar batch.
+    ///
+    /// # Return values
+    /// Ok(Some(batch)): The next rows, of which there are fewer than max_rows if the input ended.
+    /// Ok(None): The input has ended, or max_rows is 0.
+    /// Err(e): A record could not be read or parsed, or a column's parser returned
+    /// values of different types. The rows read before it are dropped.
+    pub fn read_batch(&mut self, max_rows: usize) -> Result<Option<ArgumentsBatch>, CsvError>
+    {
+        let mut rows = Vec::new();
+        let mut numbers = Vec::new();
+        
+        while rows.len() < max_rows
+        {
+            let Some(row) = self.read_row()?
+            else { break };
+            
+            rows.push(row);
+            numbers.push(self.row);
+        }
+        
+        if rows.is_empty()
+        {
+            return Ok(None);
+        }
+        
+        ArgumentsBatch::from_rows(&rows)
+            .map(Some)
+            .map_err(|e|
+            {
+                // Batch columns follow the declaration order, rather than the order of the fields.
+                let (row, column) =
+                match e
+                {
+                    BatchError::RowLength { row, .. } => (numbers[row], None),
+                    // These are only raised by validation, which is not done here.
+                    BatchError::ColumnCount { .. } | BatchError::Constraint { .. } => (numbers[0], None),
+                    BatchError::ColumnType { row, column } =>
+                    (numbers[row.unwrap_or(0)],
+                     self.order.as_ref().and_then(|o| o.iter().position(|&d| d == column)).map(|c| c + 1))
+                };
+                
+                CsvError { row, column, kind: CsvErrorKind::Batch(e) }
+            })
+    }
+}
+
+impl<R> Iterator for CsvReader<R>
+where
+    R: Read
+{
+    type Item = Result<Arguments<'static>, CsvError>;
+    
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.read_row().transpose()
+    }
+}
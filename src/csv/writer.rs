@@ -0,0 +1,197 @@
+use std::{
+    any::Any,
+    io::Write
+};
+
+use crate::{text::render_builtin, Arguments, ArgumentsBatch};
+
+use super::{CsvError, CsvErrorKind};
+
+/// Writes a field, quoting it if it holds a comma, a quote or a line break.
+fn write_field(field: &str, output: &mut String)
+{
+    if field.contains([',', '"', '\r', '\n'])
+    {
+        output.push('"');
+        output.push_str(&field.replace('"', "\"\""));
+        output.push('"');
+    }
+    else
+    {
+        output.push_str(field);
+    }
+}
+
+/// A streaming CSV writer, rendering Arguments as records.
+///
+/// Records follow RFC 4180, and end with CRLF. Fields holding a comma, a quote
+/// or a line break are quoted, as are records whose only field is empty.
+#[derive(Debug)]
+pub struct CsvWriter<W>
+{
+    sink: W,
+    /// The number of records written.
+    row: usize,
+    record: String
+}
+
+impl<W> CsvWriter<W>
+where
+    W: Write
+{
+    /// Creates a writer over sink.
+    #[inline(always)]
+    pub fn new(sink: W) -> Self
+    {
+        Self
+        {
+            sink,
+            row: 0,
+            record: String::new()
+        }
+    }
+    
+    /// Returns the number of records written.
+    #[inline(always)]
+    pub fn rows(&self) -> usize
+    {
+        self.row
+    }
+    
+    /// Writes the buffered record into the sink.
+    ///
+    /// A record whose only field is empty is quoted, as a blank line would be skipped.
+    fn finish_record(&mut self, fields: usize) -> Result<(), CsvError>
+    {
+        if fields == 1 && self.record.is_empty()
+        {
+            self.record.push_str("\"\"");
+        }
+        
+        self.record.push_str("\r\n");
+        self.row += 1;
+        
+        self.sink
+            .write_all(self.record.as_bytes())
+            .map_err(|e| CsvError { row: self.row, column: None, kind: CsvErrorKind::Io(e) })
+    }
+    
+    /// Writes a record of raw fields, such as a header.
+    ///
+    /// # Return values
+    /// Ok(()): The record was written.
+    /// Err(e): Writing into the sink failed.
+    pub fn write_record<I, S>(&mut self, fields: I) -> Result<(), CsvError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        self.record.clear();
+        
+        let mut count = 0;
+        
+        for field in fields
+        {
+            if count != 0
+            {
+                self.record.push(',');
+            }
+            
+            write_field(field.as_ref(), &mut self.record);
+            
+            count += 1;
+        }
+        
+        self.finish_record(count)
+    }
+    
+    /// Writes the arguments as a record.
+    ///
+    /// Supported types are the ones implementing ToText within this crate, along
+    /// with the other string types read by Argument::downcast_str.
+    ///
+    /// # Return values
+    /// Ok(()): The record was written.
+    /// Err(e): An argument is not supported, in which case nothing is written, or
+    /// writing into the sink failed.
+    #[inline(always)]
+    pub fn write_row(&mut self, args: &Arguments<'_>) -> Result<(), CsvError>
+    {
+        self.write_row_with(args, |_| None)
+    }
+    
+    /// Writes the arguments as a record, with a fallback for other types.
+    ///
+    /// The fallback is called for arguments without a built-in rendering, and
+    /// usually downcasts the value before calling ToText on it.
+    ///
+    /// # Return values
+    /// Refer to CsvWriter::write_row.
+    pub fn write_row_with<F>(&mut self, args: &Arguments<'_>, fallback: F) -> Result<(), CsvError>
+    where
+        F: Fn(&dyn Any) -> Option<String>
+    {
+        self.record.clear();
+        
+        for (idx, arg) in args.iter().enumerate()
+        {
+            let text =
+            render_builtin(arg)
+                .or_else(|| fallback(&**arg))
+                .ok_or(CsvError { row: self.row + 1, column: Some(idx + 1), kind: CsvErrorKind::Unsupported })?;
+            
+            if idx != 0
+            {
+                self.record.push(',');
+            }
+            
+            write_field(&text, &mut self.record);
+        }
+        
+        self.finish_record(args.len())
+    }
+    
+    /// Writes each row of a batch as a record.
+    ///
+    /// # Return values
+    /// Refer to CsvWriter::write_row. Rows before the failing one stay written.
+    #[inline(always)]
+    pub fn write_batch(&mut self, batch: &ArgumentsBatch) -> Result<(), CsvError>
+    {
+        self.write_batch_with(batch, |_| None)
+    }
+    
+    /// Writes each row of a batch as a record, with a fallback for other types.
+    ///
+    /// The fallback is called as in CsvWriter::write_row_with.
+    ///
+    /// # Return values
+    /// Refer to CsvWriter::write_batch.
+    pub fn write_batch_with<F>(&mut self, batch: &ArgumentsBatch, fallback: F) -> Result<(), CsvError>
+    where
+        F: Fn(&dyn Any) -> Option<String>
+    {
+        for row in batch.iter_rows()
+        {
+            self.write_row_with(&row, &fallback)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Flushes the sink.
+    #[inline(always)]
+    pub fn flush(&mut self) -> Result<(), CsvError>
+    {
+        self.sink
+            .flush()
+            .map_err(|e| CsvError { row: self.row, column: None, kind: CsvErrorKind::Io(e) })
+    }
+    
+    /// Consumes the writer, returning the sink.
+    #[inline(always)]
+    pub fn into_inner(self) -> W
+    {
+        self.sink
+    }
+}
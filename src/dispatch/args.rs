@@ -0,0 +1,70 @@
+#[cfg(no_std)]
+use core::{any::Any, ops::Deref};
+
+#[cfg(not(no_std))]
+use std::{any::Any, ops::Deref};
+
+use crate::{Argument, OwnedArgument};
+
+use super::casts::CastTable;
+
+/// The arguments handed to a MultiMethod's handler.
+///
+/// Besides dereferencing into the arguments themselves, this can cast
+/// arguments into the targets registered through MultiMethod::register_cast.
+#[derive(Clone, Copy)]
+pub struct DispatchArgs<'m, 'a>
+{
+    args: &'m [Argument<'a>],
+    casts: &'m CastTable,
+    /// Promoted copies of the borrowed slices which have casts, by index.
+    /// This is empty if there are none.
+    promoted: &'m [Option<OwnedArgument>]
+}
+
+impl<'m, 'a> Deref for DispatchArgs<'m, 'a>
+{
+    type Target = [Argument<'a>];
+    
+    #[inline(always)]
+    fn deref(&self) -> &[Argument<'a>]
+    {
+        self.args
+    }
+}
+
+impl<'m, 'a> DispatchArgs<'m, 'a>
+{
+    #[inline(always)]
+    pub(crate) fn new(args: &'m [Argument<'a>], casts: &'m CastTable, promoted: &'m [Option<OwnedArgument>]) -> Self
+    {
+        Self
+        {
+            args,
+            casts,
+            promoted
+        }
+    }
+    
+    /// Casts an argument into U through a registered cast.
+    ///
+    /// Borrowed strings and slices are cast as String and `Vec<T>`, as they are keyed.
+    ///
+    /// Returns None if the argument is out of bounds, or if there is
+    /// no cast from its type into U.
+    #[inline(always)]
+    pub fn cast<U>(&self, idx: usize) -> Option<&'m U>
+    where
+        U: ?Sized + 'static
+    {
+        let value : &'m dyn Any =
+        match self.promoted.get(idx)
+        {
+            Some(Some(promoted)) => &**promoted,
+            _ => &**self.args.get(idx)?
+        };
+        
+        self.casts
+            .cast(value)
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap as Map,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::any::{Any, TypeId};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap as Map
+};
+
+/// A type-erased cast from a concrete type into U, which is usually a trait object.
+type Caster<U> = Box<dyn Fn(&dyn Any) -> Option<&U>>;
+
+/// A registered cast, whose caster is a boxed `Caster<U>`.
+struct Cast
+{
+    to: TypeId,
+    caster: Box<dyn Any>
+}
+
+/// The casts registered on a MultiMethod, keyed by their source type.
+#[derive(Default)]
+pub(crate) struct CastTable
+{
+    casts: Map<TypeId, Vec<Cast>>
+}
+
+impl CastTable
+{
+    /// Registers a cast from T into U, replacing any previous cast between the two.
+    pub fn register<T, U>(&mut self, cast: fn(&T) -> &U)
+    where
+        T: Any,
+        U: ?Sized + 'static
+    {
+        let caster : Caster<U> =
+        Box::new(move |value| value.downcast_ref::<T>().map(cast));
+        
+        let entry = self.casts.entry(TypeId::of::<T>()).or_default();
+        
+        entry.retain(|c| c.to != TypeId::of::<U>());
+        entry.push(Cast { to: TypeId::of::<U>(), caster: Box::new(caster) });
+    }
+    
+    /// Iterates over the targets of each cast from a type, in the order they were registered.
+    #[inline(always)]
+    pub fn targets(&self, from: TypeId) -> impl Iterator<Item = TypeId> + '_
+    {
+        self.casts
+            .get(&from)
+            .into_iter()
+            .flatten()
+            .map(|c| c.to)
+    }
+    
+    /// Checks if there is any cast from a type.
+    #[inline(always)]
+    pub fn contains(&self, from: TypeId) -> bool
+    {
+        self.casts
+            .get(&from)
+            .is_some_and(|c| !c.is_empty())
+    }
+    
+    /// Casts a value into U.
+    ///
+    /// Returns None if there is no cast from the value's type into U.
+    #[inline(always)]
+    pub fn cast<'v, U>(&self, value: &'v dyn Any) -> Option<&'v U>
+    where
+        U: ?Sized + 'static
+    {
+        self.casts
+            .get(&(*value).type_id())?
+            .iter()
+            .find(|c| c.to == TypeId::of::<U>())?
+            .caster
+            .downcast_ref::<Caster<U>>()
+            .and_then(|caster| caster(value))
+    }
+}
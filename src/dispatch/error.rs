@@ -0,0 +1,31 @@
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+/// An error raised when a MultiMethod cannot dispatch a call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DispatchError
+{
+    /// There are fewer arguments than the MultiMethod's arity, and there is no default handler.
+    TooFewArguments { expected: usize, found: usize },
+    /// No handler matches the argument types, and there is no default handler.
+    NoHandler
+}
+
+impl fmt::Display for DispatchError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::TooFewArguments { expected, found } =>
+            write!(f, "expected at least {expected} arguments, found {found}"),
+            Self::NoHandler =>
+            f.write_str("no handler matches the argument types")
+        }
+    }
+}
+
+impl error::Error for DispatchError {}
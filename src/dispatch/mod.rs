@@ -0,0 +1,8 @@
+mod args;
+mod casts;
+mod error;
+mod multimethod;
+
+pub use args::DispatchArgs;
+pub use error::DispatchError;
+pub use multimethod::MultiMethod;
@@ -0,0 +1,261 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap as Map,
+    vec,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{
+    any::{Any, TypeId},
+    fmt
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap as Map,
+    fmt
+};
+
+use crate::{Argument, OwnedArgument};
+
+use super::{casts::CastTable, DispatchArgs, DispatchError};
+
+/// A handler, receiving every argument of the call.
+type Handler<R> = Box<dyn Fn(DispatchArgs<'_, '_>) -> R>;
+
+/// A table of handlers, keyed on the types of the first arity arguments.
+///
+/// Handlers are found through a hash of the argument types, so the lookup does not
+/// depend on the amount of handlers. Under no_std, the table is ordered instead.
+///
+/// When no handler matches the exact types, each argument may be replaced by the
+/// targets of its registered casts, which are usually trait objects such as
+/// `dyn Entity`. The combinations are tried in order, with earlier arguments
+/// keeping their exact types the longest, and casts in the order they were registered.
+/// Failing that, the default handler is called.
+///
+/// Borrowed strings and slices are keyed as String and `Vec<T>`, and can be read
+/// through Argument::downcast_deref.
+pub struct MultiMethod<R>
+{
+    arity: usize,
+    handlers: Map<Box<[TypeId]>, Handler<R>>,
+    casts: CastTable,
+    default: Option<Handler<R>>
+}
+
+impl<R> fmt::Debug for MultiMethod<R>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("MultiMethod")
+         .field("arity", &self.arity)
+         .field("handlers", &self.handlers.len())
+         .field("has_default", &self.default.is_some())
+         .finish()
+    }
+}
+
+impl<R> MultiMethod<R>
+{
+    /// Creates an empty table, keyed on the first arity arguments.
+    #[inline(always)]
+    pub fn new(arity: usize) -> Self
+    {
+        Self
+        {
+            arity,
+            handlers: Map::default(),
+            casts: CastTable::default(),
+            default: None
+        }
+    }
+    
+    /// Returns the amount of arguments the handlers are keyed on.
+    #[inline(always)]
+    pub fn arity(&self) -> usize
+    {
+        self.arity
+    }
+    
+    /// Returns the amount of handlers, not counting the default one.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.handlers.len()
+    }
+    
+    /// Checks if there are no handlers, not counting the default one.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.handlers.is_empty()
+    }
+    
+    /// Registers a handler for a tuple of argument types, replacing any previous one.
+    ///
+    /// The types may include the targets of registered casts.
+    ///
+    /// # Return values
+    /// Ok(()): The handler was registered.
+    /// Err(handler): The amount of types does not match the arity.
+    #[inline(always)]
+    pub fn register<F>(&mut self, types: &[TypeId], handler: F) -> Result<(), F>
+    where
+        F: Fn(DispatchArgs<'_, '_>) -> R + 'static
+    {
+        if types.len() != self.arity
+        {
+            return Err(handler);
+        }
+        
+        self.handlers.insert(types.into(), Box::new(handler));
+        
+        Ok(())
+    }
+    
+    /// Registers a cast from T into U, which is tried when no handler matches T itself.
+    ///
+    /// Handlers receive the original argument, and can cast it through DispatchArgs::cast.
+    #[inline(always)]
+    pub fn register_cast<T, U>(&mut self, cast: fn(&T) -> &U)
+    where
+        T: Any,
+        U: ?Sized + 'static
+    {
+        self.casts.register(cast);
+    }
+    
+    /// Sets the handler called when nothing else matches.
+    #[inline(always)]
+    pub fn set_default<F>(&mut self, handler: F)
+    where
+        F: Fn(DispatchArgs<'_, '_>) -> R + 'static
+    {
+        self.default = Some(Box::new(handler));
+    }
+    
+    /// Finds the handler for a tuple of argument types, trying registered casts.
+    fn lookup(&self, types: &[TypeId]) -> Option<&Handler<R>>
+    {
+        if let Some(handler) = self.handlers.get(types)
+        {
+            return Some(handler);
+        }
+        
+        // The candidates of each argument: its exact type, followed by its cast targets.
+        let candidates : Vec<Vec<TypeId>> =
+        types.iter()
+             .map(|t| core::iter::once(*t).chain(self.casts.targets(*t)).collect())
+             .collect();
+        
+        let mut choice = Vec::from(types);
+        let mut indices = vec![0_usize; types.len()];
+        
+        // Counts through each combination, with the last argument changing the fastest.
+        loop
+        {
+            let mut position = indices.len();
+            
+            loop
+            {
+                if position == 0
+                {
+                    return None;
+                }
+                
+                position -= 1;
+                indices[position] += 1;
+                
+                if indices[position] < candidates[position].len()
+                {
+                    break;
+                }
+                
+                indices[position] = 0;
+            }
+            
+            for (slot, (idx, list)) in choice.iter_mut().zip(indices.iter().zip(&candidates))
+            {
+                *slot = list[*idx];
+            }
+            
+            if let Some(handler) = self.handlers.get(&*choice)
+            {
+                return Some(handler);
+            }
+        }
+    }
+    
+    /// Checks if a call with the given arguments would reach a handler other than the default.
+    #[inline(always)]
+    pub fn contains(&self, args: &[Argument<'_>]) -> bool
+    {
+        args.len() >= self.arity
+        && self.lookup(&key(&args[..self.arity])).is_some()
+    }
+    
+    /// Calls the handler matching the types of the first arity arguments.
+    ///
+    /// Every argument is handed to the handler, including the ones past the arity.
+    ///
+    /// # Return values
+    /// Ok(r): The result of the matching handler, or of the default handler.
+    /// Err(e): There are too few arguments, or nothing matches, and there is no default handler.
+    pub fn dispatch(&self, args: &[Argument<'_>]) -> Result<R, DispatchError>
+    {
+        let handler =
+        if args.len() >= self.arity
+        {
+            self.lookup(&key(&args[..self.arity]))
+        }
+        else { None };
+        
+        let handler =
+        match (handler, &self.default)
+        {
+            (Some(h), _) | (None, Some(h)) => h,
+            (None, None) if args.len() < self.arity =>
+            return Err(DispatchError::TooFewArguments { expected: self.arity, found: args.len() }),
+            (None, None) => return Err(DispatchError::NoHandler)
+        };
+        
+        let promoted = self.promote_castable(args);
+        
+        Ok(handler(DispatchArgs::new(args, &self.casts, &promoted)))
+    }
+    
+    /// Promotes the borrowed slices which have casts, as casts need a reference
+    /// to the String or `Vec<T>` they are keyed as.
+    ///
+    /// Returns an empty set, without allocating, if there are no such slices.
+    fn promote_castable(&self, args: &[Argument<'_>]) -> Vec<Option<OwnedArgument>>
+    {
+        let castable = |a: &Argument<'_>|
+        a.handle()
+         .promoted_type_id()
+         .is_some_and(|t| self.casts.contains(t));
+        
+        if !args.iter().any(castable)
+        {
+            return Vec::new();
+        }
+        
+        args.iter()
+            .map(|a| castable(a).then(|| a.handle().clone_object()))
+            .collect()
+    }
+}
+
+/// Collects the types of a set of arguments.
+#[inline(always)]
+fn key(args: &[Argument<'_>]) -> Vec<TypeId>
+{
+    args.iter()
+        .map(Argument::value_type_id)
+        .collect()
+}
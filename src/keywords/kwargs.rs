@@ -0,0 +1,319 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    vec::Vec,
+    vec::IntoIter as VecIntoIter
+};
+
+#[cfg(no_std)]
+use core::{
+    any::Any,
+    mem
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    mem,
+    vec::IntoIter as VecIntoIter
+};
+
+use crate::{Argument, CapacityError, MAX_ARG_COUNT};
+
+/// A container for storing a set of named arguments.
+///
+/// Entries are kept in insertion order, and each key is unique.
+/// Similarly to ArgumentsBuilder, the amount of entries is no
+/// more than MAX_ARG_COUNT.
+#[derive(Clone, Debug, Default)]
+pub struct KeywordArguments<'a>
+{
+    /// The inner table for storing each key and argument pair.
+    table: Vec<(Box<str>, Argument<'a>)>
+}
+
+impl KeywordArguments<'_>
+{
+    /// Creates a new, empty instance of KeywordArguments.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self
+        {
+            table: Vec::new()
+        }
+    }
+    
+    /// Creates a new instance of KeywordArguments with a set capacity.
+    ///
+    /// The capacity is restricted to MAX_ARG_COUNT elements.
+    #[inline(always)]
+    pub fn with_capacity(cap: usize) -> Self
+    {
+        let cap = if cap <= MAX_ARG_COUNT { cap } else { MAX_ARG_COUNT };
+        
+        Self
+        {
+            table: Vec::with_capacity(cap)
+        }
+    }
+    
+    /// Returns the amount of entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.table.len()
+    }
+    
+    /// Checks if there are no entries.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.table.is_empty()
+    }
+    
+    /// Determines whether or not the set is full.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool
+    {
+        self.len() >= MAX_ARG_COUNT
+    }
+    
+    /// Returns the position of the entry named key.
+    #[inline(always)]
+    fn position(&self, key: &str) -> Option<usize>
+    {
+        self.table
+            .iter()
+            .position(|(k, _)| &**k == key)
+    }
+    
+    /// Checks if an entry named key exists.
+    #[inline(always)]
+    pub fn contains_key(&self, key: &str) -> bool
+    {
+        self.position(key).is_some()
+    }
+}
+
+impl<'a> KeywordArguments<'a>
+{
+    /// Tries to insert a generic, owned item under key.
+    ///
+    /// # Return values
+    /// Ok(Some(arg)): The item replaced the previous argument, arg.
+    /// Ok(None): The item was inserted as a new entry.
+    /// Err(owned): The key is new, and the set is already full.
+    #[inline(always)]
+    pub fn insert_owned<K, T>(&mut self, key: K, owned: T)
+    -> Result<Option<Argument<'a>>, T>
+    where
+        K: Into<Box<str>>,
+        T: Any + Clone
+    {
+        let key = key.into();
+        
+        match self.position(&key)
+        {
+            Some(idx) =>
+            {
+                let previous =
+                mem::replace(&mut self.table[idx].1,
+                                   Argument::new_owned(owned));
+                
+                Ok(Some(previous))
+            }
+            None if !self.is_full() =>
+            {
+                self.table.push((key, Argument::new_owned(owned)));
+                Ok(None)
+            }
+            None => Err(owned)
+        }
+    }
+    
+    /// Tries to insert an argument under key.
+    ///
+    /// # Return values
+    /// Ok(Some(arg)): The argument replaced the previous argument, arg.
+    /// Ok(None): The argument was inserted as a new entry.
+    /// Err(arg): The key is new, and the set is already full.
+    #[inline(always)]
+    pub fn insert<K>(&mut self, key: K, arg: Argument<'a>)
+    -> Result<Option<Argument<'a>>, Argument<'a>>
+    where
+        K: Into<Box<str>>
+    {
+        let key = key.into();
+        
+        match self.position(&key)
+        {
+            Some(idx) => Ok(Some(mem::replace(&mut self.table[idx].1, arg))),
+            None if !self.is_full() =>
+            {
+                self.table.push((key, arg));
+                Ok(None)
+            }
+            None => Err(arg)
+        }
+    }
+    
+    /// Inserts an argument under a key that is known to fit, returning the previous argument.
+    ///
+    /// Used where the amount of keys is bounded by MAX_ARG_COUNT elsewhere,
+    /// such as by a spec or by another set of keyword arguments.
+    #[inline(always)]
+    pub(crate) fn insert_bounded<K>(&mut self, key: K, arg: Argument<'a>) -> Option<Argument<'a>>
+    where
+        K: Into<Box<str>>
+    {
+        let inserted = self.insert(key, arg);
+        
+        debug_assert!(inserted.is_ok(), "the amount of keys exceeds MAX_ARG_COUNT");
+        
+        inserted.ok().flatten()
+    }
+    
+    /// Inserts each entry in order, replacing the argument of keys that are already set.
+    ///
+    /// Entries with new keys that do not fit are dropped, while the remaining
+    /// entries are still inserted, so that existing keys take their last value.
+    ///
+    /// # Return values
+    /// Ok(()): Every entry was inserted.
+    /// Err(e): Some entries did not fit. The index of e is the first dropped entry,
+    /// counted from zero across entries.
+    pub fn try_extend<I, K>(&mut self, entries: I) -> Result<(), CapacityError>
+    where
+        I: IntoIterator<Item = (K, Argument<'a>)>,
+        K: Into<Box<str>>
+    {
+        let mut overflow : Option<CapacityError> = None;
+        
+        for (index, (key, arg)) in entries.into_iter().enumerate()
+        {
+            if self.insert(key, arg).is_err()
+            {
+                match &mut overflow
+                {
+                    Some(e) => e.rejected += 1,
+                    None => overflow = Some(CapacityError { index, rejected: 1 })
+                }
+            }
+        }
+        
+        overflow.map_or(Ok(()), Err)
+    }
+    
+    /// Tries to insert a generic, borrowed item under key.
+    ///
+    /// # Return values
+    /// true: The item was inserted, or replaced a previous entry.
+    /// false: The key is new, and the set is already full.
+    #[inline(always)]
+    pub fn insert_borrowed<K, T>(&mut self, key: K, borrowed: &'a T) -> bool
+    where
+        K: Into<Box<str>>,
+        T: Any + Clone
+    {
+        self.insert(key, Argument::new_borrowed(borrowed))
+            .is_ok()
+    }
+    
+    /// Creates a new set of keyword arguments, borrowing each argument from self.
+    ///
+    /// Refer to Arguments::borrow_all for more information.
+    #[inline(always)]
+    pub fn borrow_all(&self) -> KeywordArguments<'_>
+    {
+        KeywordArguments
+        {
+            table:
+            self.table
+                .iter()
+                .map(|(k, v)| (k.clone(), v.as_ref()))
+                .collect()
+        }
+    }
+    
+    /// Consumes the set, returning a set that does not borrow anything.
+    ///
+    /// Refer to Argument::into_static for more information.
+    #[inline(always)]
+    pub fn into_owned(self) -> KeywordArguments<'static>
+    {
+        KeywordArguments
+        {
+            table:
+            self.table
+                .into_iter()
+                .map(|(k, v)| (k, v.into_static()))
+                .collect()
+        }
+    }
+    
+    /// Acquires a reference to the argument named key.
+    #[inline(always)]
+    pub fn get(&self, key: &str) -> Option<&Argument<'a>>
+    {
+        self.position(key)
+            .map(|idx| &self.table[idx].1)
+    }
+    
+    /// Acquires a mutable reference to the argument named key.
+    #[inline(always)]
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Argument<'a>>
+    {
+        self.position(key)
+            .map(|idx| &mut self.table[idx].1)
+    }
+    
+    /// Removes the argument named key, keeping the order of the remaining entries.
+    #[inline(always)]
+    pub fn remove(&mut self, key: &str) -> Option<Argument<'a>>
+    {
+        self.position(key)
+            .map(|idx| self.table.remove(idx).1)
+    }
+    
+    /// Iterates over each key in insertion order.
+    #[inline(always)]
+    pub fn keys(&self) -> impl Iterator<Item = &str>
+    {
+        self.table
+            .iter()
+            .map(|(k, _)| &**k)
+    }
+    
+    /// Iterates over a borrowed set of entries in insertion order.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Argument<'a>)>
+    {
+        self.table
+            .iter()
+            .map(|(k, v)| (&**k, v))
+    }
+    
+    /// Iterates over a mutable set of entries in insertion order.
+    ///
+    /// Only the arguments are accessed mutably, which keeps each key unique.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Argument<'a>)>
+    {
+        self.table
+            .iter_mut()
+            .map(|(k, v)| (&**k, v))
+    }
+}
+
+impl<'a> IntoIterator for KeywordArguments<'a>
+{
+    type Item = (Box<str>, Argument<'a>);
+    type IntoIter = VecIntoIter<(Box<str>, Argument<'a>)>;
+    
+    #[inline(always)]
+    fn into_iter(self) -> VecIntoIter<(Box<str>, Argument<'a>)>
+    {
+        self.table.into_iter()
+    }
+}
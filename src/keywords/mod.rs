@@ -0,0 +1,5 @@
+mod kwargs;
+mod stack;
+
+pub use kwargs::KeywordArguments;
+pub use stack::{ArgumentsStack, LayerDiff};
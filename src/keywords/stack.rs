@@ -0,0 +1,294 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    vec::Vec
+};
+
+use crate::{Argument, CapacityError, KeywordArguments};
+
+/// A single, named layer of an ArgumentsStack.
+#[derive(Clone, Debug)]
+struct Layer<'a>
+{
+    name: Box<str>,
+    args: KeywordArguments<'a>
+}
+
+/// A difference between two layers of an ArgumentsStack.
+#[derive(Clone, Copy, Debug)]
+pub enum LayerDiff<'s, 'a>
+{
+    /// The key only exists in the upper layer.
+    Added { key: &'s str, value: &'s Argument<'a> },
+    /// The key only exists in the lower layer.
+    Removed { key: &'s str, value: &'s Argument<'a> },
+    /// The key exists in both layers, and the upper value shadows the lower one.
+    Overridden { key: &'s str, lower: &'s Argument<'a>, upper: &'s Argument<'a> }
+}
+
+impl<'s> LayerDiff<'s, '_>
+{
+    /// Returns the key the difference is about.
+    #[inline(always)]
+    pub fn key(&self) -> &'s str
+    {
+        match self
+        {
+            Self::Added { key, .. } |
+            Self::Removed { key, .. } |
+            Self::Overridden { key, .. } => key
+        }
+    }
+}
+
+/// A stack of named argument sets, where upper layers override lower ones.
+///
+/// Layers are pushed from lowest to highest precedence, such as defaults, then
+/// a user profile, then a per-call override. Lookups resolve from the top layer
+/// down, so a key in an upper layer shadows the same key in every layer below.
+///
+/// Layers pushed through ArgumentsStack::push_borrowed only hold borrowed
+/// arguments. These stay borrowed until they are mutated, at which point
+/// Argument::to_mut clones them.
+#[derive(Clone, Debug, Default)]
+pub struct ArgumentsStack<'a>
+{
+    /// The layers, from lowest to highest precedence.
+    layers: Vec<Layer<'a>>
+}
+
+impl<'a> ArgumentsStack<'a>
+{
+    /// Creates a new stack without any layers.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self
+        {
+            layers: Vec::new()
+        }
+    }
+    
+    /// Returns the amount of layers.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.layers.len()
+    }
+    
+    /// Checks if there are no layers.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.layers.is_empty()
+    }
+    
+    /// Pushes a layer on top of the stack.
+    #[inline(always)]
+    pub fn push_layer<N>(&mut self, name: N, args: KeywordArguments<'a>)
+    where
+        N: Into<Box<str>>
+    {
+        self.layers.push(Layer { name: name.into(), args });
+    }
+    
+    /// Pushes a layer on top of the stack, consuming and returning the stack.
+    #[inline(always)]
+    pub fn with_layer<N>(mut self, name: N, args: KeywordArguments<'a>) -> Self
+    where
+        N: Into<Box<str>>
+    {
+        self.push_layer(name, args);
+        self
+    }
+    
+    /// Pushes a layer borrowing each argument of args.
+    ///
+    /// No argument is cloned until it is mutated.
+    #[inline(always)]
+    pub fn push_borrowed<N>(&mut self, name: N, args: &'a KeywordArguments<'a>)
+    where
+        N: Into<Box<str>>
+    {
+        self.push_layer(name, args.borrow_all());
+    }
+    
+    /// Removes the top layer, returning its name and arguments.
+    #[inline(always)]
+    pub fn pop_layer(&mut self) -> Option<(Box<str>, KeywordArguments<'a>)>
+    {
+        self.layers
+            .pop()
+            .map(|l| (l.name, l.args))
+    }
+    
+    /// Iterates over the name of each layer, from lowest to highest precedence.
+    #[inline(always)]
+    pub fn layer_names(&self) -> impl Iterator<Item = &str>
+    {
+        self.layers
+            .iter()
+            .map(|l| &*l.name)
+    }
+    
+    /// Acquires the arguments of the layer at idx, with 0 being the lowest layer.
+    #[inline(always)]
+    pub fn layer(&self, idx: usize) -> Option<&KeywordArguments<'a>>
+    {
+        self.layers
+            .get(idx)
+            .map(|l| &l.args)
+    }
+    
+    /// Acquires the arguments of the layer at idx mutably.
+    #[inline(always)]
+    pub fn layer_mut(&mut self, idx: usize) -> Option<&mut KeywordArguments<'a>>
+    {
+        self.layers
+            .get_mut(idx)
+            .map(|l| &mut l.args)
+    }
+    
+    /// Returns the index of the layer named name.
+    ///
+    /// If several layers share a name, the highest one is returned.
+    #[inline(always)]
+    pub fn position(&self, name: &str) -> Option<usize>
+    {
+        self.layers
+            .iter()
+            .rposition(|l| &*l.name == name)
+    }
+    
+    /// Returns the index of the layer the value of key resolves from.
+    #[inline(always)]
+    pub fn layer_of(&self, key: &str) -> Option<usize>
+    {
+        self.layers
+            .iter()
+            .rposition(|l| l.args.contains_key(key))
+    }
+    
+    /// Returns the name of the layer the value of key resolves from.
+    #[inline(always)]
+    pub fn layer_name_of(&self, key: &str) -> Option<&str>
+    {
+        self.layer_of(key)
+            .map(|idx| &*self.layers[idx].name)
+    }
+    
+    /// Checks if any layer holds key.
+    #[inline(always)]
+    pub fn contains_key(&self, key: &str) -> bool
+    {
+        self.layer_of(key).is_some()
+    }
+    
+    /// Acquires the value of key from the highest layer holding it.
+    #[inline(always)]
+    pub fn get(&self, key: &str) -> Option<&Argument<'a>>
+    {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|l| l.args.get(key))
+    }
+    
+    /// Acquires the value of key mutably, from the highest layer holding it.
+    ///
+    /// The value stays in its layer. Borrowed values are only cloned once
+    /// mutated through Argument::to_mut.
+    #[inline(always)]
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Argument<'a>>
+    {
+        self.layers
+            .iter_mut()
+            .rev()
+            .find_map(|l| l.args.get_mut(key))
+    }
+    
+    /// Tries to set the value of key in the top layer, overriding every layer below.
+    ///
+    /// # Return values
+    /// Ok(Some(arg)): The argument replaced the previous top layer argument, arg.
+    /// Ok(None): The argument was inserted as a new entry of the top layer.
+    /// Err(arg): There are no layers, or the top layer is already full.
+    #[inline(always)]
+    pub fn set<K>(&mut self, key: K, arg: Argument<'a>)
+    -> Result<Option<Argument<'a>>, Argument<'a>>
+    where
+        K: Into<Box<str>>
+    {
+        match self.layers.last_mut()
+        {
+            Some(top) => top.args.insert(key, arg),
+            None => Err(arg)
+        }
+    }
+    
+    /// Merges the layers into a single set, borrowing each resolved argument.
+    ///
+    /// Keys are ordered by their first appearance, from the lowest layer up.
+    ///
+    /// # Return values
+    /// Ok(flat): The merged set.
+    /// Err(e): The layers hold more than MAX_ARG_COUNT distinct keys. The index of e
+    /// is counted across the entries of every layer, from the lowest layer up.
+    pub fn flatten(&self) -> Result<KeywordArguments<'_>, CapacityError>
+    {
+        let mut flat = KeywordArguments::new();
+        
+        flat.try_extend(self.layers
+                            .iter()
+                            .flat_map(|l| l.args.iter())
+                            .map(|(k, a)| (k, a.as_ref())))?;
+        
+        Ok(flat)
+    }
+    
+    /// Consumes the stack, merging the layers into a single set.
+    ///
+    /// Refer to ArgumentsStack::flatten for the ordering of keys.
+    ///
+    /// # Return values
+    /// Refer to ArgumentsStack::flatten. On failure, the stack is dropped.
+    pub fn into_flattened(self) -> Result<KeywordArguments<'a>, CapacityError>
+    {
+        let mut flat = KeywordArguments::new();
+        
+        flat.try_extend(self.layers
+                            .into_iter()
+                            .flat_map(|l| l.args))?;
+        
+        Ok(flat)
+    }
+    
+    /// Lists the differences between the layers at lower and upper.
+    ///
+    /// Added and Overridden entries follow the order of the upper layer,
+    /// followed by Removed entries in the order of the lower layer.
+    ///
+    /// Returns None if either index is out of bounds.
+    pub fn diff(&self, lower: usize, upper: usize) -> Option<Vec<LayerDiff<'_, 'a>>>
+    {
+        let lower = &self.layers.get(lower)?.args;
+        let upper = &self.layers.get(upper)?.args;
+        
+        let mut diff : Vec<LayerDiff<'_, 'a>> =
+        upper.iter()
+            .map(|(key, value)| match lower.get(key)
+            {
+                Some(l) => LayerDiff::Overridden { key, lower: l, upper: value },
+                None => LayerDiff::Added { key, value }
+            })
+            .collect();
+        
+        diff.extend(
+            lower.iter()
+                .filter(|(key, _)| !upper.contains_key(key))
+                .map(|(key, value)| LayerDiff::Removed { key, value })
+        );
+        
+        Some(diff)
+    }
+}
@@ -8,22 +8,102 @@
 //!
 //! Use [Arguments] for parsing arguments.
 //!
+//! Use [ArgumentsBatch] for calling a function over many rows of arguments.
+//!
+//! Use [KeywordArguments] for named arguments, and [Template] for rendering them.
+//!
 //! [ArgumentsBuilder]: ArgumentsBuilder
 //! [Arguments]: Arguments
+//! [ArgumentsBatch]: ArgumentsBatch
+//! [KeywordArguments]: KeywordArguments
+//! [Template]: Template
 
 #[cfg(no_std)]
 extern crate alloc;
 
 mod argument;
 mod arguments;
+#[cfg(not(no_std))]
+mod argv;
+mod batch;
+mod binding;
+mod cli;
+mod config;
+mod conversion;
+#[cfg(not(no_std))]
+mod csv;
+mod dispatch;
+mod keywords;
+mod query;
+mod schema;
+mod shell;
+mod signature;
+mod template;
+mod text;
 
-pub use argument::{OwnedArgument, Argument, ArgumentKind};
-pub use arguments::{Arguments, ArgumentsBuilder, MAX_ARG_COUNT};
+pub use argument::{OwnedArgument, Argument, ArgumentKind, Coercion, CoerceError, DerefArgument};
+#[doc(hidden)]
+pub use argument::ArgumentSwitch;
+pub use arguments::{Arguments, ArgumentsBuilder, ArgumentsRef, CapacityError, FromArguments, JoinError, MAX_ARG_COUNT};
+#[cfg(not(no_std))]
+pub use argv::{ArgvError, FlagStyle, ToArgv};
+pub use batch::{ArgumentsBatch, BatchError};
+pub use binding::{BindError, BoundArguments, ParamSpec, ParamSpecError};
+pub use cli::{CliError, CliErrorKind, CommandSpec, CommandSpecError, ParsedCommand};
+pub use config::{Config, ConfigError, ConfigErrorKind, ValueSource};
+pub use conversion::{Conversion, ConversionRegistry, Converted, Converter};
+#[cfg(not(no_std))]
+pub use csv::{CsvError, CsvErrorKind, CsvReader, CsvWriter};
+pub use dispatch::{DispatchArgs, DispatchError, MultiMethod};
+pub use keywords::{ArgumentsStack, KeywordArguments, LayerDiff};
+pub use query::{decode_query, encode_query, encode_query_with, QueryError};
+pub use schema::{Schema, SchemaError, TokenParser};
+pub use shell::{CommandShell, ShellError, ShellOutput, TokenizeError};
+pub use signature::{
+    Constraint,
+    MatchKind,
+    OverloadError,
+    OverloadSet,
+    Signature,
+    SignatureError,
+    Violation,
+    ViolationKind
+};
+pub use template::{Alignment, FormatKind, FormatSpec, Template, TemplateError};
+pub use text::ToText;
 //pub mod borrowed_arg;
 
 #[cfg(test)]
 mod tests
 {
+    // Newer toolchains flag the unread field of a sample type in this module.
+    #[allow(dead_code)]
     mod owned_argument;
     mod argument;
+    mod template;
+    mod switch;
+    mod coerce;
+    mod conversion;
+    mod deref;
+    mod borrowed_slice;
+    mod ownership;
+    mod views;
+    mod builder;
+    mod join;
+    mod typed;
+    mod homogeneous;
+    mod batch;
+    mod overload;
+    mod multimethod;
+    mod binding;
+    mod constraint;
+    mod cli;
+    mod shell;
+    #[cfg(not(no_std))]
+    mod argv;
+    mod config;
+    mod stack;
+    mod query;
+    #[cfg(not(no_std))]
+    mod csv;
 }
@@ -0,0 +1,100 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+use crate::{KeywordArguments, Schema, MAX_ARG_COUNT};
+
+use super::QueryError;
+
+/// Decodes a percent-encoded form component.
+///
+/// offset and pair are the positions of the component and of its pair within
+/// the query, for error reporting.
+fn decode_component(text: &str, offset: usize, pair: usize) -> Result<String, QueryError>
+{
+    let bytes = text.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    
+    while idx < bytes.len()
+    {
+        match bytes[idx]
+        {
+            b'+' => output.push(b' '),
+            b'%' =>
+            {
+                let byte =
+                text.get(idx + 1..idx + 3)
+                    .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                    .ok_or(QueryError::InvalidEscape { position: offset + idx })?;
+                
+                output.push(byte);
+                idx += 2;
+            }
+            b => output.push(b)
+        }
+        
+        idx += 1;
+    }
+    
+    String::from_utf8(output)
+        .map_err(|_| QueryError::InvalidUtf8 { position: pair })
+}
+
+/// Decodes an `application/x-www-form-urlencoded` string into keyword arguments.
+///
+/// Pairs are separated by `&`, and a pair without `=` has an empty value. Both keys
+/// and values are percent-decoded, with `+` standing for a space. Values are parsed
+/// through the schema into owned arguments, with the keys declared through
+/// Schema::repeated collecting every value into a `Vec<T>`. Keys are kept in order
+/// of their first appearance.
+///
+/// # Return values
+/// Ok(kwargs): The decoded arguments.
+/// Err(e): The query is malformed, a key is unknown, a value is invalid, or a key
+/// that is not repeated appears more than once.
+pub fn decode_query(query: &str, schema: &Schema) -> Result<KeywordArguments<'static>, QueryError>
+{
+    let mut grouped : Vec<(String, Vec<String>)> = Vec::new();
+    let mut position = 0;
+    
+    for pair in query.split('&')
+    {
+        let start = position;
+        position += pair.len() + 1;
+        
+        if pair.is_empty()
+        {
+            continue;
+        }
+        
+        let (raw_key, raw_value) = pair.split_once('=').unwrap_or((pair, ""));
+        
+        let key = decode_component(raw_key, start, start)?;
+        let value = decode_component(raw_value, start + raw_key.len() + 1, start)?;
+        
+        let position = grouped.iter().position(|(k, _)| *k == key);
+        
+        match position
+        {
+            Some(idx) => grouped[idx].1.push(value),
+            None if grouped.len() < MAX_ARG_COUNT => grouped.push((key, Vec::from([value]))),
+            None => return Err(QueryError::TooManyKeys)
+        }
+    }
+    
+    let parsed =
+    grouped.into_iter()
+           .map(|(key, values)| schema.parse_all(&key, &values).map(|arg| (key, arg)))
+           .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut kwargs = KeywordArguments::with_capacity(parsed.len());
+    
+    kwargs.try_extend(parsed)
+          .map_err(|_| QueryError::TooManyKeys)?;
+    
+    Ok(kwargs)
+}
@@ -0,0 +1,84 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{any::Any, fmt::Write};
+
+#[cfg(not(no_std))]
+use std::{any::Any, fmt::Write};
+
+use crate::{text::render_builtin_list, KeywordArguments};
+
+use super::QueryError;
+
+/// Percent-encodes text as a form component, writing it into output.
+///
+/// ASCII alphanumerics and `*-._` are kept, spaces become `+`, and every
+/// other byte is escaped as `%XX`.
+fn encode_component(text: &str, output: &mut String)
+{
+    for byte in text.bytes()
+    {
+        match byte
+        {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => output.push(byte as char),
+            b' ' => output.push('+'),
+            // Writing into a String does not fail.
+            _ => { let _ = write!(output, "%{byte:02X}"); }
+        }
+    }
+}
+
+/// Encodes keyword arguments as an `application/x-www-form-urlencoded` string.
+///
+/// Supported values are strings, the integer and float primitives, bool and char,
+/// along with vectors and slices of them. Vectors and slices repeat their key once
+/// for each element, and an empty vector leaves its key out.
+///
+/// # Return values
+/// Ok(query): Every argument was encoded.
+/// Err(QueryError::Unsupported): The key of the first argument that is not supported.
+#[inline(always)]
+pub fn encode_query(kwargs: &KeywordArguments<'_>) -> Result<String, QueryError>
+{
+    encode_query_with(kwargs, |_| None)
+}
+
+/// Encodes keyword arguments as a query string, with a fallback for other types.
+///
+/// The fallback is called for arguments without a built-in rendering, and returns
+/// one text for each value of the key.
+///
+/// # Return values
+/// Refer to encode_query.
+pub fn encode_query_with<F>(kwargs: &KeywordArguments<'_>, fallback: F) -> Result<String, QueryError>
+where
+    F: Fn(&dyn Any) -> Option<Vec<String>>
+{
+    let mut output = String::new();
+    
+    for (key, arg) in kwargs.iter()
+    {
+        let values =
+        render_builtin_list(arg)
+            .or_else(|| fallback(&**arg))
+            .ok_or_else(|| QueryError::Unsupported { key: key.into() })?;
+        
+        for value in values
+        {
+            if !output.is_empty()
+            {
+                output.push('&');
+            }
+            
+            encode_component(key, &mut output);
+            output.push('=');
+            encode_component(&value, &mut output);
+        }
+    }
+    
+    Ok(output)
+}
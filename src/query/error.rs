@@ -0,0 +1,62 @@
+#[cfg(no_std)]
+use alloc::boxed::Box;
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+use crate::SchemaError;
+
+/// An error raised while encoding or decoding a query string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QueryError
+{
+    /// The value of key has no text rendering.
+    Unsupported { key: Box<str> },
+    /// A `%` at position, a byte offset into the query, is not followed by two hex digits.
+    InvalidEscape { position: usize },
+    /// The pair starting at position decodes into invalid UTF-8.
+    InvalidUtf8 { position: usize },
+    /// The query holds more distinct keys than MAX_ARG_COUNT.
+    TooManyKeys,
+    /// A key is unknown, or one of its values is invalid.
+    Schema(SchemaError)
+}
+
+impl From<SchemaError> for QueryError
+{
+    #[inline(always)]
+    fn from(e: SchemaError) -> Self
+    {
+        Self::Schema(e)
+    }
+}
+
+impl fmt::Display for QueryError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Unsupported { key } => write!(f, "the value of '{key}' cannot be rendered as text"),
+            Self::InvalidEscape { position } => write!(f, "invalid percent escape at position {position}"),
+            Self::InvalidUtf8 { position } => write!(f, "invalid UTF-8 in the pair at position {position}"),
+            Self::TooManyKeys => f.write_str("too many keys"),
+            Self::Schema(e) => write!(f, "{e}")
+        }
+    }
+}
+
+impl error::Error for QueryError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match self
+        {
+            Self::Schema(e) => Some(e),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod decode;
+mod encode;
+mod error;
+
+pub use decode::decode_query;
+pub use encode::{encode_query, encode_query_with};
+pub use error::QueryError;
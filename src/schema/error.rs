@@ -0,0 +1,35 @@
+#[cfg(no_std)]
+use alloc::boxed::Box;
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+/// An error raised when a value cannot be parsed through a Schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaError
+{
+    /// The schema does not declare the key.
+    UnknownKey { key: Box<str> },
+    /// The value could not be parsed into, or is not of, the key's declared type.
+    InvalidValue { key: Box<str>, expected: &'static str },
+    /// The key takes a single value, but was given found values.
+    ValueCount { key: Box<str>, found: usize }
+}
+
+impl fmt::Display for SchemaError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::UnknownKey { key } => write!(f, "unknown key '{key}'"),
+            Self::InvalidValue { key, expected } => write!(f, "invalid value for '{key}': expected {expected}"),
+            Self::ValueCount { key, found } => write!(f, "'{key}' takes a single value, found {found}")
+        }
+    }
+}
+
+impl error::Error for SchemaError {}
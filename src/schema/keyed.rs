@@ -0,0 +1,237 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::{
+    any::{self, Any, TypeId},
+    str::FromStr
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{self, Any, TypeId},
+    str::FromStr
+};
+
+use crate::{Argument, OwnedArgument};
+
+use super::{short_type_name, SchemaError, TokenParser, ValueType};
+
+/// A function collecting the parsed values of a repeated key into a single argument.
+type Collector = fn(Vec<OwnedArgument>) -> OwnedArgument;
+
+/// Collects parsed values of type T into a `Vec<T>`.
+fn collect_vec<T>(items: Vec<OwnedArgument>) -> OwnedArgument
+where
+    T: Any + Clone
+{
+    let items : Vec<T> =
+    items.into_iter()
+         .filter_map(|i| i.downcast_owned::<T>().ok())
+         .collect();
+    
+    OwnedArgument::new(items)
+}
+
+/// A declared key.
+#[derive(Clone, Copy, Debug)]
+struct KeyEntry
+{
+    /// The type of each value.
+    value: ValueType,
+    /// The collector of repeated keys, along with the name of the collected type.
+    repeated: Option<(Collector, &'static str)>,
+    /// The type of the parsed argument, unless the key has a custom parser.
+    type_id: Option<TypeId>
+}
+
+/// A set of keys, each with a declared type and a parser for it.
+///
+/// Keys are kept in the order they were declared. Schemas are used to turn
+/// text, such as configuration files or query strings, into typed arguments.
+#[derive(Clone, Debug, Default)]
+pub struct Schema
+{
+    entries: Vec<(Box<str>, KeyEntry)>
+}
+
+impl Schema
+{
+    /// Creates an empty schema.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+    
+    /// Declares a key whose values are parsed through a custom parser.
+    ///
+    /// Declaring an existing key replaces its type, while keeping its position.
+    /// The type of the parsed values is not known, so Schema::check accepts any type for the key.
+    pub fn key_with(self, key: &str, type_name: &'static str, parse: TokenParser) -> Self
+    {
+        self.declare(key, KeyEntry { value: ValueType { type_name, parse }, repeated: None, type_id: None })
+    }
+    
+    #[inline(always)]
+    fn declare(mut self, key: &str, entry: KeyEntry) -> Self
+    {
+        match self.entries.iter_mut().find(|(k, _)| **k == *key)
+        {
+            Some((_, e)) => *e = entry,
+            None => self.entries.push((key.into(), entry))
+        }
+        
+        self
+    }
+    
+    /// Declares a key of type T, whose values are parsed through FromStr.
+    ///
+    /// Refer to Schema::key_with.
+    #[inline(always)]
+    pub fn key<T>(self, key: &str) -> Self
+    where
+        T: FromStr + Any + Clone
+    {
+        self.declare(key, KeyEntry { value: ValueType::of::<T>(), repeated: None, type_id: Some(TypeId::of::<T>()) })
+    }
+    
+    /// Declares a key which may be given several times, collecting its values into a `Vec<T>`.
+    ///
+    /// Each value is parsed through FromStr. Parsing a single value through
+    /// Schema::parse results in a `Vec<T>` of one element.
+    #[inline(always)]
+    pub fn repeated<T>(self, key: &str) -> Self
+    where
+        T: FromStr + Any + Clone
+    {
+        let entry =
+        KeyEntry
+        {
+            value: ValueType::of::<T>(),
+            repeated: Some((collect_vec::<T>, short_type_name(any::type_name::<Vec<T>>()))),
+            type_id: Some(TypeId::of::<Vec<T>>())
+        };
+        
+        self.declare(key, entry)
+    }
+    
+    /// Returns the amount of keys.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.entries.len()
+    }
+    
+    /// Checks if the schema has no keys.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+    
+    /// Checks if the schema declares a key.
+    #[inline(always)]
+    pub fn contains(&self, key: &str) -> bool
+    {
+        self.entry(key).is_some()
+    }
+    
+    /// Checks if the schema declares a key through Schema::repeated.
+    #[inline(always)]
+    pub fn is_repeated(&self, key: &str) -> bool
+    {
+        self.entry(key).is_some_and(|e| e.repeated.is_some())
+    }
+    
+    /// Iterates over each key, along with the name of its type, in declaration order.
+    #[inline(always)]
+    pub fn keys(&self) -> impl Iterator<Item = (&str, &'static str)>
+    {
+        self.entries
+            .iter()
+            .map(|(k, e)| (&**k, e.repeated.map_or(e.value.type_name, |(_, name)| name)))
+    }
+    
+    #[inline(always)]
+    fn entry(&self, key: &str) -> Option<&KeyEntry>
+    {
+        self.entries
+            .iter()
+            .find(|(k, _)| **k == *key)
+            .map(|(_, e)| e)
+    }
+    
+    /// Checks if a value of type T may be stored under a key.
+    ///
+    /// Repeated keys take a `Vec<T>`. Keys declared through Schema::key_with take any type.
+    ///
+    /// # Return values
+    /// Ok(()): The key is declared, and takes values of type T.
+    /// Err(e): The key is not declared, or takes values of another type.
+    pub fn check<T>(&self, key: &str) -> Result<(), SchemaError>
+    where
+        T: Any
+    {
+        let entry =
+        self.entry(key)
+            .ok_or_else(|| SchemaError::UnknownKey { key: key.into() })?;
+        
+        match entry.type_id
+        {
+            Some(id) if id != TypeId::of::<T>() =>
+            {
+                let expected = entry.repeated.map_or(entry.value.type_name, |(_, name)| name);
+                
+                Err(SchemaError::InvalidValue { key: key.into(), expected })
+            }
+            _ => Ok(())
+        }
+    }
+    
+    /// Parses a value for a key into an owned argument of the key's type.
+    ///
+    /// # Return values
+    /// Ok(arg): The parsed value.
+    /// Err(e): The key is not declared, or the value is not valid for its type.
+    #[inline(always)]
+    pub fn parse(&self, key: &str, value: &str) -> Result<Argument<'static>, SchemaError>
+    {
+        self.parse_all(key, &[value])
+    }
+    
+    /// Parses every value given for a key into a single owned argument.
+    ///
+    /// Repeated keys collect their values into a `Vec<T>`, while other keys
+    /// take exactly one value.
+    ///
+    /// # Return values
+    /// Ok(arg): The parsed value.
+    /// Err(e): The key is not declared, a value is not valid for its type,
+    /// or a key that is not repeated has a value count other than one.
+    pub fn parse_all<S>(&self, key: &str, values: &[S]) -> Result<Argument<'static>, SchemaError>
+    where
+        S: AsRef<str>
+    {
+        let entry =
+        self.entry(key)
+            .ok_or_else(|| SchemaError::UnknownKey { key: key.into() })?;
+        
+        let parse =
+        |value: &S|
+        (entry.value.parse)(value.as_ref())
+            .ok_or_else(|| SchemaError::InvalidValue { key: key.into(), expected: entry.value.type_name });
+        
+        match (entry.repeated, values)
+        {
+            (Some((collect, _)), values) =>
+            {
+                let items = values.iter().map(parse).collect::<Result<Vec<_>, _>>()?;
+                
+                Ok(collect(items).into())
+            }
+            (None, [value]) => parse(value).map(Argument::from),
+            (None, values) => Err(SchemaError::ValueCount { key: key.into(), found: values.len() })
+        }
+    }
+}
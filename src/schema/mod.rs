@@ -0,0 +1,8 @@
+mod error;
+mod keyed;
+mod value;
+
+pub use error::SchemaError;
+pub use keyed::Schema;
+pub use value::TokenParser;
+pub(crate) use value::{short_type_name, ValueType};
@@ -0,0 +1,69 @@
+#[cfg(no_std)]
+use core::{
+    any::{self, Any},
+    str::FromStr
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{self, Any},
+    str::FromStr
+};
+
+use crate::OwnedArgument;
+
+/// A function parsing a token of text into an owned argument.
+///
+/// Returns None if the token is not a valid value.
+pub type TokenParser = fn(&str) -> Option<OwnedArgument>;
+
+/// Parses a token through FromStr.
+#[inline(always)]
+fn parse_from_str<T>(token: &str) -> Option<OwnedArgument>
+where
+    T: FromStr + Any + Clone
+{
+    token.parse::<T>()
+         .ok()
+         .map(OwnedArgument::new)
+}
+
+/// Shortens a type name by removing the module path of its outermost type.
+///
+/// For instance, `alloc::string::String` becomes `String`. The paths within
+/// generic arguments are kept, so `alloc::vec::Vec<alloc::string::String>`
+/// becomes `Vec<alloc::string::String>`.
+pub(crate) fn short_type_name(name: &'static str) -> &'static str
+{
+    let end = name.find('<').unwrap_or(name.len());
+    
+    match name[..end].rfind("::")
+    {
+        Some(idx) => &name[idx + 2..],
+        None => name
+    }
+}
+
+/// A typed value parsed from a token.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ValueType
+{
+    pub type_name: &'static str,
+    pub parse: TokenParser
+}
+
+impl ValueType
+{
+    /// Creates a value type parsed through FromStr.
+    #[inline(always)]
+    pub fn of<T>() -> Self
+    where
+        T: FromStr + Any + Clone
+    {
+        Self
+        {
+            type_name: short_type_name(any::type_name::<T>()),
+            parse: parse_from_str::<T>
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    format,
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+use crate::CliError;
+
+/// An error raised when a line cannot be split into tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenizeError
+{
+    /// A quote is never closed. The position is the byte offset of the opening quote.
+    UnclosedQuote { position: usize },
+    /// The line ends with a backslash, which has nothing to escape.
+    TrailingEscape
+}
+
+impl fmt::Display for TokenizeError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::UnclosedQuote { position } => write!(f, "unclosed quote at position {position}"),
+            Self::TrailingEscape => f.write_str("trailing backslash")
+        }
+    }
+}
+
+impl error::Error for TokenizeError {}
+
+/// An error raised while evaluating a line in a CommandShell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShellError
+{
+    /// The line could not be split into tokens.
+    Tokenize(TokenizeError),
+    /// No command or alias has the given name.
+    UnknownCommand { name: Box<str> },
+    /// The command's parameters could not be parsed. The tokens after
+    /// the command's name are kept, so that the error can be rendered.
+    Command
+    {
+        name: Box<str>,
+        error: CliError,
+        tokens: Vec<String>
+    }
+}
+
+impl ShellError
+{
+    /// Renders the error, pointing at the offending token when there is one.
+    ///
+    /// Refer to CliError::render for more information.
+    pub fn render(&self) -> String
+    {
+        match self
+        {
+            Self::Command { name, error, tokens } => format!("{name}: {}", error.render(tokens)),
+            _ => format!("error: {self}")
+        }
+    }
+}
+
+impl fmt::Display for ShellError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Tokenize(e) => fmt::Display::fmt(e, f),
+            Self::UnknownCommand { name } => write!(f, "unknown command '{name}'"),
+            Self::Command { name, error, .. } => write!(f, "{name}: {error}")
+        }
+    }
+}
+
+impl error::Error for ShellError {}
+
+impl From<TokenizeError> for ShellError
+{
+    #[inline(always)]
+    fn from(error: TokenizeError) -> Self
+    {
+        Self::Tokenize(error)
+    }
+}
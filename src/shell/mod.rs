@@ -0,0 +1,6 @@
+mod error;
+mod registry;
+mod tokenize;
+
+pub use error::{ShellError, TokenizeError};
+pub use registry::{CommandShell, ShellOutput};
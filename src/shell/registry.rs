@@ -0,0 +1,248 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::fmt::{self, Write};
+
+#[cfg(not(no_std))]
+use std::fmt::{self, Write};
+
+use crate::{CliErrorKind, CommandSpec, CommandSpecError, ParsedCommand};
+
+use super::{tokenize::tokenize, ShellError, TokenizeError};
+
+/// A command handler, receiving the parsed parameters.
+type Handler<R> = Box<dyn Fn(ParsedCommand) -> R>;
+
+/// The result of evaluating a line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShellOutput<R>
+{
+    /// The line is empty.
+    Empty,
+    /// A built-in command, or `--help`, produced text.
+    Text(String),
+    /// A registered command returned a value.
+    Value(R)
+}
+
+/// A registered command.
+struct Entry<R>
+{
+    spec: CommandSpec,
+    aliases: Vec<Box<str>>,
+    handler: Handler<R>
+}
+
+/// A set of commands, evaluated from single lines of text.
+///
+/// A line such as `move 10 20 --speed 2.5` is split into tokens with shell-style
+/// quoting. The first token names the command, or one of its aliases, and the rest
+/// are parsed through the command's CommandSpec before calling its handler.
+///
+/// Two commands are built in: `list`, which lists every command, and `help`,
+/// which prints the help text of a command, or the list when given no name.
+/// `name --help` also prints the help text of a command.
+pub struct CommandShell<R>
+{
+    commands: Vec<Entry<R>>
+}
+
+impl<R> fmt::Debug for CommandShell<R>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_list()
+         .entries(self.commands.iter().map(|e| e.spec.name()))
+         .finish()
+    }
+}
+
+impl<R> Default for CommandShell<R>
+{
+    #[inline(always)]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<R> CommandShell<R>
+{
+    /// Creates a shell with only the built-in commands.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self
+        {
+            commands: Vec::new()
+        }
+    }
+    
+    /// Splits a line into tokens, as done by CommandShell::eval.
+    ///
+    /// Refer to the quoting rules of POSIX shells: single quotes keep every character,
+    /// double quotes allow escaping `"`, `\` and `$`, and backslashes escape anything
+    /// outside of quotes.
+    #[inline(always)]
+    pub fn tokenize(line: &str) -> Result<Vec<String>, TokenizeError>
+    {
+        tokenize(line)
+    }
+    
+    /// Checks if a name is used by a command, an alias or a built-in command.
+    #[inline(always)]
+    pub fn contains(&self, name: &str) -> bool
+    {
+        name == "help" || name == "list" || self.find(name).is_some()
+    }
+    
+    /// Finds a command by its name or one of its aliases.
+    #[inline(always)]
+    fn find(&self, name: &str) -> Option<&Entry<R>>
+    {
+        self.commands
+            .iter()
+            .find(|e| e.spec.name() == name || e.aliases.iter().any(|a| **a == *name))
+    }
+    
+    /// Registers a command under the name of its spec.
+    ///
+    /// # Return values
+    /// Ok(()): The command was registered.
+    /// Err(CommandSpecError::DuplicateName): The name is already in use.
+    pub fn register<F>(&mut self, spec: CommandSpec, handler: F) -> Result<(), CommandSpecError>
+    where
+        F: Fn(ParsedCommand) -> R + 'static
+    {
+        if self.contains(spec.name())
+        {
+            return Err(CommandSpecError::DuplicateName { name: spec.name().into() });
+        }
+        
+        self.commands.push(Entry
+        {
+            spec,
+            aliases: Vec::new(),
+            handler: Box::new(handler)
+        });
+        
+        Ok(())
+    }
+    
+    /// Adds an alias for a registered command.
+    ///
+    /// # Return values
+    /// true: The alias was added.
+    /// false: Either there is no command named command, or the alias is already in use.
+    pub fn alias(&mut self, command: &str, alias: &str) -> bool
+    {
+        if self.contains(alias)
+        {
+            return false;
+        }
+        
+        match self.commands.iter_mut().find(|e| e.spec.name() == command)
+        {
+            Some(entry) =>
+            {
+                entry.aliases.push(alias.into());
+                true
+            }
+            None => false
+        }
+    }
+    
+    /// Lists every command along with its aliases, one per line.
+    pub fn list(&self) -> String
+    {
+        let mut output = String::from("commands:");
+        
+        // Writing into a String does not fail.
+        for entry in &self.commands
+        {
+            let _ = write!(output, "\n  {}", entry.spec.name());
+            
+            if !entry.aliases.is_empty()
+            {
+                let _ = write!(output, " (aliases: {})", entry.aliases.join(", "));
+            }
+        }
+        
+        output.push_str("\n  help [command]\n  list");
+        
+        output
+    }
+    
+    /// Evaluates a single line.
+    ///
+    /// # Return values
+    /// Ok(output): Refer to ShellOutput.
+    /// Err(e): The line could not be tokenized, the command is unknown,
+    /// or its parameters could not be parsed.
+    pub fn eval(&self, line: &str) -> Result<ShellOutput<R>, ShellError>
+    {
+        let tokens = tokenize(line)?;
+        
+        let Some((name, rest)) = tokens.split_first()
+        else
+        {
+            return Ok(ShellOutput::Empty);
+        };
+        
+        match (name.as_str(), rest)
+        {
+            ("list", _) | ("help", []) => return Ok(ShellOutput::Text(self.list())),
+            ("help", [command, ..]) =>
+            {
+                let entry =
+                self.find(command)
+                    .ok_or_else(|| ShellError::UnknownCommand { name: command.as_str().into() })?;
+                
+                return Ok(ShellOutput::Text(entry.spec.help()));
+            }
+            _ => ()
+        }
+        
+        let entry =
+        self.find(name)
+            .ok_or_else(|| ShellError::UnknownCommand { name: name.as_str().into() })?;
+        
+        match entry.spec.parse(rest)
+        {
+            Ok(parsed) => Ok(ShellOutput::Value((entry.handler)(parsed))),
+            Err(e) =>
+            match e.kind
+            {
+                CliErrorKind::HelpRequested { help } => Ok(ShellOutput::Text(help.into())),
+                _ => Err(ShellError::Command
+                {
+                    name: name.as_str().into(),
+                    error: e,
+                    tokens: Vec::from(rest)
+                })
+            }
+        }
+    }
+}
+
+/// Formats a ShellOutput whose value can be displayed, writing nothing for Empty.
+impl<R> fmt::Display for ShellOutput<R>
+where
+    R: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Empty => Ok(()),
+            Self::Text(t) => f.write_str(t),
+            Self::Value(v) => fmt::Display::fmt(v, f)
+        }
+    }
+}
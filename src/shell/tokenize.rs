@@ -0,0 +1,84 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+use super::TokenizeError;
+
+/// Splits a line into tokens, following the quoting rules of POSIX shells.
+///
+/// Tokens are separated by whitespace. Within single quotes, every character is
+/// literal. Within double quotes, a backslash only escapes `"`, `\` and `$`.
+/// Outside of quotes, a backslash escapes any character. Quotes may appear
+/// within a token, as in `--name="a b"`, and `""` produces an empty token.
+pub(crate) fn tokenize(line: &str) -> Result<Vec<String>, TokenizeError>
+{
+    let mut tokens = Vec::new();
+    
+    // The current token, which is None between tokens.
+    let mut current : Option<String> = None;
+    
+    let mut chars = line.char_indices();
+    
+    while let Some((position, c)) = chars.next()
+    {
+        match c
+        {
+            c if c.is_whitespace() =>
+            {
+                tokens.extend(current.take());
+            }
+            '\'' =>
+            {
+                let token = current.get_or_insert_with(String::new);
+                
+                loop
+                {
+                    match chars.next()
+                    {
+                        Some((_, '\'')) => break,
+                        Some((_, c)) => token.push(c),
+                        None => return Err(TokenizeError::UnclosedQuote { position })
+                    }
+                }
+            }
+            '"' =>
+            {
+                let token = current.get_or_insert_with(String::new);
+                
+                loop
+                {
+                    match chars.next()
+                    {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) =>
+                        match chars.next()
+                        {
+                            Some((_, c @ ('"' | '\\' | '$'))) => token.push(c),
+                            Some((_, c)) =>
+                            {
+                                token.push('\\');
+                                token.push(c);
+                            }
+                            None => return Err(TokenizeError::UnclosedQuote { position })
+                        },
+                        Some((_, c)) => token.push(c),
+                        None => return Err(TokenizeError::UnclosedQuote { position })
+                    }
+                }
+            }
+            '\\' =>
+            {
+                let (_, escaped) = chars.next().ok_or(TokenizeError::TrailingEscape)?;
+                
+                current.get_or_insert_with(String::new).push(escaped);
+            }
+            c => current.get_or_insert_with(String::new).push(c)
+        }
+    }
+    
+    tokens.extend(current);
+    
+    Ok(tokens)
+}
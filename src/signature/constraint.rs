@@ -0,0 +1,226 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    format,
+    string::String,
+    sync::Arc,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{
+    any::Any,
+    fmt,
+    ops::{Bound, RangeBounds}
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    fmt,
+    ops::{Bound, RangeBounds},
+    sync::Arc
+};
+
+use crate::Argument;
+
+/// A check on an argument's value.
+type Check = Arc<dyn Fn(&Argument<'_>) -> bool + Send + Sync>;
+
+/// A constraint on the value of a parameter.
+///
+/// Besides the check itself, each constraint has a description, such as
+/// `in 1..=10` or `not empty`, which is used in help output and violations.
+#[derive(Clone)]
+pub struct Constraint
+{
+    description: Box<str>,
+    check: Check
+}
+
+impl fmt::Debug for Constraint
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "Constraint({})", self.description)
+    }
+}
+
+impl fmt::Display for Constraint
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str(&self.description)
+    }
+}
+
+/// Writes a range the way it would be written in Rust.
+///
+/// Rust ranges cannot exclude their start, so those are written as
+/// comparisons instead, such as `> 0` or `> 0 and <= 10`.
+#[inline(always)]
+fn describe_range<T>(start: &Bound<T>, end: &Bound<T>) -> String
+where
+    T: fmt::Debug
+{
+    match (start, end)
+    {
+        (Bound::Excluded(s), Bound::Included(e)) => format!("> {s:?} and <= {e:?}"),
+        (Bound::Excluded(s), Bound::Excluded(e)) => format!("> {s:?} and < {e:?}"),
+        (Bound::Excluded(s), Bound::Unbounded) => format!("> {s:?}"),
+        (Bound::Included(s), _) => format!("in {s:?}{}", describe_end(end)),
+        (Bound::Unbounded, _) => format!("in {}", describe_end(end))
+    }
+}
+
+/// Writes the end of a range, starting from its `..`.
+#[inline(always)]
+fn describe_end<T>(end: &Bound<T>) -> String
+where
+    T: fmt::Debug
+{
+    match end
+    {
+        Bound::Included(e) => format!("..={e:?}"),
+        Bound::Excluded(e) => format!("..{e:?}"),
+        Bound::Unbounded => String::from("..")
+    }
+}
+
+impl Constraint
+{
+    /// Creates a constraint from a description and an arbitrary check.
+    #[inline(always)]
+    pub fn custom<F>(description: &str, check: F) -> Self
+    where
+        F: Fn(&Argument<'_>) -> bool + Send + Sync + 'static
+    {
+        Self
+        {
+            description: description.into(),
+            check: Arc::new(check)
+        }
+    }
+    
+    /// Requires a numeric value within a range.
+    ///
+    /// The argument is coerced into T first, as in Argument::coerce. Arguments
+    /// which cannot be coerced fail the constraint.
+    pub fn range<T, R>(range: R) -> Self
+    where
+        T: Any + Clone + PartialOrd + fmt::Debug + Send + Sync,
+        R: RangeBounds<T>
+    {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+        
+        let description = describe_range(&start, &end);
+        
+        Self::custom(&description,
+                     move |arg|
+                     arg.coerce::<T>()
+                        .is_some_and(|v| (start.as_ref(), end.as_ref()).contains(&v)))
+    }
+    
+    /// Requires a string which is not empty.
+    ///
+    /// Strings are read through Argument::downcast_str.
+    #[inline(always)]
+    pub fn non_empty_str() -> Self
+    {
+        Self::str_predicate("not empty", |s| !s.is_empty())
+    }
+    
+    /// Requires a slice of T which is not empty.
+    ///
+    /// Slices are read through Argument::downcast_slice, so this covers
+    /// `Vec<T>`, `Box<[T]>` and borrowed slices.
+    #[inline(always)]
+    pub fn non_empty_slice<T>() -> Self
+    where
+        T: Any + Clone
+    {
+        Self::custom("not empty",
+                     |arg|
+                     arg.downcast_slice::<T>()
+                        .is_some_and(|s| !s.is_empty()))
+    }
+    
+    /// Requires a string which satisfies a predicate.
+    ///
+    /// Arguments which are not strings fail the constraint.
+    #[inline(always)]
+    pub fn str_predicate<F>(description: &str, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static
+    {
+        Self::custom(description,
+                     move |arg|
+                     arg.downcast_str()
+                        .is_some_and(&predicate))
+    }
+    
+    /// Requires a string which starts with a prefix.
+    #[inline(always)]
+    pub fn starts_with(prefix: &str) -> Self
+    {
+        let prefix = String::from(prefix);
+        
+        Self::str_predicate(&format!("starts with {prefix:?}"),
+                            move |s| s.starts_with(&*prefix))
+    }
+    
+    /// Requires a string which ends with a suffix.
+    #[inline(always)]
+    pub fn ends_with(suffix: &str) -> Self
+    {
+        let suffix = String::from(suffix);
+        
+        Self::str_predicate(&format!("ends with {suffix:?}"),
+                            move |s| s.ends_with(&*suffix))
+    }
+    
+    /// Requires a string which contains a pattern.
+    #[inline(always)]
+    pub fn contains(pattern: &str) -> Self
+    {
+        let pattern = String::from(pattern);
+        
+        Self::str_predicate(&format!("contains {pattern:?}"),
+                            move |s| s.contains(&*pattern))
+    }
+    
+    /// Requires a value which equals one of the given values.
+    ///
+    /// The argument is coerced into T first, as in Argument::coerce.
+    pub fn one_of<T, I>(values: I) -> Self
+    where
+        T: Any + Clone + PartialEq + fmt::Debug + Send + Sync,
+        I: IntoIterator<Item = T>
+    {
+        let values : Vec<T> = values.into_iter().collect();
+        
+        let description = format!("one of {values:?}");
+        
+        Self::custom(&description,
+                     move |arg|
+                     arg.coerce::<T>()
+                        .is_some_and(|v| values.contains(&v)))
+    }
+    
+    /// Returns the description of the constraint.
+    #[inline(always)]
+    pub fn description(&self) -> &str
+    {
+        &self.description
+    }
+    
+    /// Checks an argument against the constraint.
+    #[inline(always)]
+    pub fn check(&self, arg: &Argument<'_>) -> bool
+    {
+        (self.check)(arg)
+    }
+}
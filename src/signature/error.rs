@@ -0,0 +1,154 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+use super::Signature;
+
+/// An error raised when an OverloadSet cannot pick an overload.
+#[derive(Clone, Debug)]
+pub enum OverloadError
+{
+    /// None of the overloads match the arguments. Every overload is listed.
+    NoMatch
+    {
+        name: Box<str>,
+        candidates: Vec<Signature>
+    },
+    /// More than one overload matches equally well. The tied overloads are listed.
+    Ambiguous
+    {
+        name: Box<str>,
+        candidates: Vec<Signature>
+    }
+}
+
+impl OverloadError
+{
+    /// The name of the OverloadSet.
+    #[inline(always)]
+    pub fn name(&self) -> &str
+    {
+        match self
+        {
+            Self::NoMatch { name, .. } | Self::Ambiguous { name, .. } => name
+        }
+    }
+    
+    /// The listed candidates.
+    #[inline(always)]
+    pub fn candidates(&self) -> &[Signature]
+    {
+        match self
+        {
+            Self::NoMatch { candidates, .. } | Self::Ambiguous { candidates, .. } => candidates
+        }
+    }
+}
+
+impl fmt::Display for OverloadError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::NoMatch { name, .. } => write!(f, "no overload of {name} matches the arguments")?,
+            Self::Ambiguous { name, .. } => write!(f, "call to {name} is ambiguous")?
+        }
+        
+        f.write_str("; candidates:")?;
+        
+        for candidate in self.candidates()
+        {
+            write!(f, " {}{candidate}", self.name())?;
+        }
+        
+        Ok(())
+    }
+}
+
+impl error::Error for OverloadError {}
+
+/// The reason an argument violates a signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViolationKind
+{
+    /// The argument does not have, and cannot be coerced into, the parameter's type.
+    Type { expected: &'static str },
+    /// The argument fails one of the parameter's constraints.
+    Constraint { description: Box<str> }
+}
+
+/// A single argument violating a signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation
+{
+    /// The position of the argument.
+    pub position: usize,
+    /// The name of the parameter, if it has one.
+    pub name: Option<Box<str>>,
+    /// What the argument violates.
+    pub kind: ViolationKind
+}
+
+impl fmt::Display for Violation
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match &self.name
+        {
+            Some(name) => write!(f, "argument {} ({name}) ", self.position)?,
+            None => write!(f, "argument {} ", self.position)?
+        }
+        
+        match &self.kind
+        {
+            ViolationKind::Type { expected } => write!(f, "is not of type {expected}"),
+            ViolationKind::Constraint { description } => write!(f, "is not {description}")
+        }
+    }
+}
+
+/// An error raised when a set of arguments is validated against a Signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError
+{
+    /// The argument count does not fit the signature.
+    Arity { expected: usize, variadic: bool, found: usize },
+    /// The arguments violate the signature, each of which is listed in order.
+    Violations(Vec<Violation>)
+}
+
+impl fmt::Display for SignatureError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Arity { expected, variadic: false, found } =>
+            write!(f, "expected {expected} arguments, found {found}"),
+            Self::Arity { expected, variadic: true, found } =>
+            write!(f, "expected at least {expected} arguments, found {found}"),
+            Self::Violations(violations) =>
+            {
+                for (idx, violation) in violations.iter().enumerate()
+                {
+                    if idx != 0
+                    {
+                        f.write_str("; ")?;
+                    }
+                    
+                    write!(f, "{violation}")?;
+                }
+                
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for SignatureError {}
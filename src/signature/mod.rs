@@ -0,0 +1,9 @@
+mod constraint;
+mod error;
+mod overload;
+mod sig;
+
+pub use constraint::Constraint;
+pub use error::{OverloadError, SignatureError, Violation, ViolationKind};
+pub use overload::OverloadSet;
+pub use sig::{MatchKind, Signature};
@@ -0,0 +1,191 @@
+#[cfg(no_std)]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(no_std)]
+use core::fmt;
+
+#[cfg(not(no_std))]
+use std::fmt;
+
+use crate::{Argument, Arguments};
+
+use super::{OverloadError, Signature};
+
+/// A callable overload, receiving its arguments already coerced.
+type Callable<R> = Box<dyn Fn(Arguments<'_>) -> R>;
+
+/// A named set of callables, each with its own signature.
+///
+/// Calls are resolved against every signature, picking the best match:
+/// exact matches come first, followed by coerced matches, and lastly matches
+/// that use a variadic tail. Among the same kind, fewer coercions win.
+/// A tie between the best matches is reported as ambiguous.
+///
+/// Overloads whose constraints reject the arguments do not match, so a
+/// callable never receives arguments violating its signature.
+pub struct OverloadSet<R>
+{
+    name: Box<str>,
+    overloads: Vec<(Signature, Callable<R>)>
+}
+
+impl<R> fmt::Debug for OverloadSet<R>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("OverloadSet")
+         .field("name", &self.name)
+         .field("overloads", &self.signatures().collect::<Vec<_>>())
+         .finish()
+    }
+}
+
+impl<R> OverloadSet<R>
+{
+    /// Creates an empty set.
+    #[inline(always)]
+    pub fn new(name: &str) -> Self
+    {
+        Self
+        {
+            name: name.into(),
+            overloads: Vec::new()
+        }
+    }
+    
+    /// Returns the name of the set.
+    #[inline(always)]
+    pub fn name(&self) -> &str
+    {
+        &self.name
+    }
+    
+    /// Returns the amount of overloads.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.overloads.len()
+    }
+    
+    /// Checks if the set has no overloads.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.overloads.is_empty()
+    }
+    
+    /// Adds an overload.
+    ///
+    /// Each argument the callable receives has the type of its parameter,
+    /// so it can be downcast directly.
+    #[inline(always)]
+    pub fn add<F>(&mut self, signature: Signature, callable: F)
+    where
+        F: Fn(Arguments<'_>) -> R + 'static
+    {
+        self.overloads.push((signature, Box::new(callable)));
+    }
+    
+    /// Adds an overload, returning the set for further chaining.
+    #[inline(always)]
+    pub fn with<F>(mut self, signature: Signature, callable: F) -> Self
+    where
+        F: Fn(Arguments<'_>) -> R + 'static
+    {
+        self.add(signature, callable);
+        self
+    }
+    
+    /// Iterates over the signature of each overload, in the order they were added.
+    #[inline(always)]
+    pub fn signatures(&self) -> impl Iterator<Item = &Signature>
+    {
+        self.overloads
+            .iter()
+            .map(|(s, _)| s)
+    }
+    
+    /// Creates the error for arguments that no overload matches.
+    #[inline(always)]
+    fn no_match(&self) -> OverloadError
+    {
+        OverloadError::NoMatch
+        {
+            name: self.name.clone(),
+            candidates: self.signatures().cloned().collect()
+        }
+    }
+    
+    /// Picks the best overload for a set of arguments, returning its index.
+    fn resolve_index(&self, args: &[Argument<'_>]) -> Result<usize, OverloadError>
+    {
+        let scores : Vec<_> =
+        self.overloads
+            .iter()
+            .map(|(s, _)| s.score(args))
+            .collect();
+        
+        let Some(best) = scores.iter().flatten().min()
+        else
+        {
+            return Err(self.no_match());
+        };
+        
+        let mut tied =
+        scores.iter()
+              .enumerate()
+              .filter(|(_, s)| s.as_ref() == Some(best))
+              .map(|(idx, _)| idx);
+        
+        let first = tied.next().unwrap();
+        
+        if tied.next().is_none()
+        {
+            return Ok(first);
+        }
+        
+        Err(OverloadError::Ambiguous
+        {
+            name: self.name.clone(),
+            candidates:
+            scores.iter()
+                  .zip(self.signatures())
+                  .filter(|(s, _)| s.as_ref() == Some(best))
+                  .map(|(_, sig)| sig.clone())
+                  .collect()
+        })
+    }
+    
+    /// Picks the best overload for a set of arguments, returning its signature.
+    ///
+    /// # Return values
+    /// Ok(sig): The signature of the best match.
+    /// Err(e): Either nothing matches, or the best matches are tied.
+    #[inline(always)]
+    pub fn resolve(&self, args: &[Argument<'_>]) -> Result<&Signature, OverloadError>
+    {
+        self.resolve_index(args)
+            .map(|idx| &self.overloads[idx].0)
+    }
+    
+    /// Calls the best overload for a set of arguments.
+    ///
+    /// Arguments are coerced into the types of the overload's signature first.
+    ///
+    /// # Return values
+    /// Ok(r): The result of the overload.
+    /// Err(e): Refer to OverloadSet::resolve. Constraints are checked again on the
+    /// coerced arguments, so a custom constraint which rejects them the second time
+    /// also results in OverloadError::NoMatch.
+    pub fn call(&self, args: &[Argument<'_>]) -> Result<R, OverloadError>
+    {
+        let (signature, callable) = &self.overloads[self.resolve_index(args)?];
+        
+        let args =
+        signature.coerce_args(args)
+                 .ok_or_else(|| self.no_match())?;
+        
+        Ok(callable(args))
+    }
+}
@@ -0,0 +1,583 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    string::String,
+    vec::Vec
+};
+
+#[cfg(no_std)]
+use core::{
+    any::{self, Any, TypeId},
+    fmt::{self, Write}
+};
+
+#[cfg(not(no_std))]
+use std::{
+    any::{self, Any, TypeId},
+    fmt::{self, Write}
+};
+
+use crate::{schema::short_type_name, Argument, Arguments, Coercion, OwnedArgument};
+
+use super::{Constraint, SignatureError, Violation, ViolationKind};
+
+/// How well a set of arguments matches a signature.
+///
+/// The variants are ordered from the best match to the worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind
+{
+    /// Every argument has the exact type of its parameter.
+    Exact,
+    /// At least one argument had to be coerced, following the signature's Coercion.
+    /// Borrowed slices also count as coerced into String or `Vec<T>`.
+    Coerced,
+    /// At least one argument was collected by the variadic tail.
+    Variadic
+}
+
+/// A single typed parameter.
+#[derive(Clone)]
+struct Param
+{
+    /// The TypeId of the parameter, or None if it accepts any type.
+    type_id: Option<TypeId>,
+    type_name: &'static str,
+    /// Coerces an argument of another type into the parameter's type.
+    coerce: fn(&Argument<'_>, Coercion) -> Option<OwnedArgument>,
+    name: Option<Box<str>>,
+    constraints: Vec<Constraint>
+}
+
+/// Coerces an argument into T, promoting borrowed slices.
+#[inline(always)]
+fn coerce_into<T>(arg: &Argument<'_>, coercion: Coercion) -> Option<OwnedArgument>
+where
+    T: Any + Clone
+{
+    // Borrowed slices are promoted in every mode, as their value is of type T.
+    if arg.handle().is_borrowed_slice()
+    {
+        let promoted = arg.handle().clone_object();
+        
+        return promoted.is_type::<T>().then_some(promoted);
+    }
+    
+    if coercion == Coercion::Exact
+    {
+        return None;
+    }
+    
+    arg.coerce_with::<T>(coercion)
+       .map(OwnedArgument::new)
+}
+
+impl Param
+{
+    #[inline(always)]
+    fn of<T>() -> Self
+    where
+        T: Any + Clone
+    {
+        Self
+        {
+            type_id: Some(TypeId::of::<T>()),
+            type_name: short_type_name(any::type_name::<T>()),
+            coerce: coerce_into::<T>,
+            name: None,
+            constraints: Vec::new()
+        }
+    }
+    
+    #[inline(always)]
+    fn any() -> Self
+    {
+        Self
+        {
+            type_id: None,
+            type_name: "_",
+            coerce: |_, _| None,
+            name: None,
+            constraints: Vec::new()
+        }
+    }
+    
+    /// Checks an argument against the parameter.
+    ///
+    /// # Return values
+    /// Some(true): The argument has the exact type.
+    /// Some(false): The argument can be coerced.
+    /// None: The argument does not match.
+    #[inline(always)]
+    fn check(&self, arg: &Argument<'_>, coercion: Coercion) -> Option<bool>
+    {
+        match self.type_id
+        {
+            None => Some(true),
+            Some(id) if (**arg).type_id() == id => Some(true),
+            Some(_) => (self.coerce)(arg, coercion).map(|_| false)
+        }
+    }
+    
+    /// Checks the constraints against an argument which passed check.
+    ///
+    /// Constraints see the argument after it is coerced, so exact is the result of check.
+    #[inline(always)]
+    fn satisfies(&self, arg: &Argument<'_>, exact: bool, coercion: Coercion) -> bool
+    {
+        if self.constraints.is_empty()
+        {
+            return true;
+        }
+        
+        let coerced =
+        if exact { None }
+        else { (self.coerce)(arg, coercion).map(Argument::from) };
+        
+        let value = coerced.as_ref().unwrap_or(arg);
+        
+        self.constraints
+            .iter()
+            .all(|c| c.check(value))
+    }
+    
+    /// Writes the parameter as `name: type`, or as `type` if it has no name.
+    #[inline(always)]
+    fn write_to(&self, f: &mut impl Write) -> fmt::Result
+    {
+        if let Some(name) = &self.name
+        {
+            write!(f, "{name}: ")?;
+        }
+        
+        f.write_str(self.type_name)
+    }
+}
+
+/// The parameter types of a callable.
+///
+/// A signature consists of a fixed list of parameters, optionally followed
+/// by a variadic tail that collects any amount of further arguments.
+///
+/// Signatures are built by chaining, as in:
+/// `Signature::new().param::<i64>().variadic::<f64>()`.
+///
+/// Parameters may be named and carry constraints on their values, which are
+/// checked by Signature::validate. Both apply to the most recently added parameter.
+///
+/// Arguments must have the exact type of their parameter, unless the signature opts
+/// into coercion through Signature::with_coercion. Arguments of another numeric type
+/// are then coerced following its Coercion.
+#[derive(Clone, Default)]
+pub struct Signature
+{
+    params: Vec<Param>,
+    variadic: Option<Param>,
+    coercion: Coercion,
+    /// Whether or not the variadic tail was added after the last fixed parameter.
+    tail_last: bool
+}
+
+impl fmt::Debug for Signature
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "Signature{self}")
+    }
+}
+
+impl fmt::Display for Signature
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str("(")?;
+        
+        for (idx, param) in self.params.iter().enumerate()
+        {
+            if idx != 0
+            {
+                f.write_str(", ")?;
+            }
+            
+            param.write_to(f)?;
+        }
+        
+        if let Some(tail) = &self.variadic
+        {
+            if !self.params.is_empty()
+            {
+                f.write_str(", ")?;
+            }
+            
+            tail.write_to(f)?;
+            f.write_str("...")?;
+        }
+        
+        f.write_str(")")
+    }
+}
+
+impl Signature
+{
+    /// Creates a signature without any parameters.
+    #[inline(always)]
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+    
+    /// Adds a parameter of type T.
+    #[inline(always)]
+    pub fn param<T>(mut self) -> Self
+    where
+        T: Any + Clone
+    {
+        self.params.push(Param::of::<T>());
+        self.tail_last = false;
+        self
+    }
+    
+    /// Adds a parameter that accepts any type.
+    #[inline(always)]
+    pub fn param_any(mut self) -> Self
+    {
+        self.params.push(Param::any());
+        self.tail_last = false;
+        self
+    }
+    
+    /// Sets the variadic tail, which collects any amount of arguments of type T.
+    #[inline(always)]
+    pub fn variadic<T>(mut self) -> Self
+    where
+        T: Any + Clone
+    {
+        self.variadic = Some(Param::of::<T>());
+        self.tail_last = true;
+        self
+    }
+    
+    /// Sets the variadic tail, which collects any amount of arguments of any type.
+    #[inline(always)]
+    pub fn variadic_any(mut self) -> Self
+    {
+        self.variadic = Some(Param::any());
+        self.tail_last = true;
+        self
+    }
+    
+    /// Acquires the most recently added parameter.
+    #[inline(always)]
+    fn last_mut(&mut self) -> Option<&mut Param>
+    {
+        if self.tail_last
+        {
+            self.variadic.as_mut()
+        }
+        else { self.params.last_mut() }
+    }
+    
+    /// Names the most recently added parameter.
+    ///
+    /// Names are used by Display and Signature::validate. Without any
+    /// parameters, this does nothing.
+    #[inline(always)]
+    pub fn named(mut self, name: &str) -> Self
+    {
+        if let Some(param) = self.last_mut()
+        {
+            param.name = Some(name.into());
+        }
+        
+        self
+    }
+    
+    /// Adds a constraint to the most recently added parameter.
+    ///
+    /// Constraints on the variadic tail apply to each argument it collects.
+    /// Without any parameters, this does nothing.
+    #[inline(always)]
+    pub fn constrain(mut self, constraint: Constraint) -> Self
+    {
+        if let Some(param) = self.last_mut()
+        {
+            param.constraints.push(constraint);
+        }
+        
+        self
+    }
+    
+    /// Sets how strictly argument types are matched, consuming and returning the signature.
+    ///
+    /// Signatures start with Coercion::Exact, where every argument must have the exact
+    /// type of its parameter. Coercion::Lossless and Coercion::Lossy opt into coercion.
+    #[inline(always)]
+    pub fn with_coercion(mut self, coercion: Coercion) -> Self
+    {
+        self.coercion = coercion;
+        self
+    }
+    
+    /// Returns how strictly argument types are matched.
+    #[inline(always)]
+    pub fn coercion(&self) -> Coercion
+    {
+        self.coercion
+    }
+    
+    /// Returns the amount of fixed parameters.
+    #[inline(always)]
+    pub fn len(&self) -> usize
+    {
+        self.params.len()
+    }
+    
+    /// Checks if the signature has no fixed parameters.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool
+    {
+        self.params.is_empty()
+    }
+    
+    /// Checks if the signature has a variadic tail.
+    #[inline(always)]
+    pub fn is_variadic(&self) -> bool
+    {
+        self.variadic.is_some()
+    }
+    
+    /// Acquires the parameter for an argument's position.
+    #[inline(always)]
+    fn param_at(&self, idx: usize) -> Option<&Param>
+    {
+        self.params
+            .get(idx)
+            .or(self.variadic.as_ref())
+    }
+    
+    /// Returns the type of the parameter for an argument's position.
+    ///
+    /// Returns None if there is no such parameter, or if it accepts any type.
+    #[inline(always)]
+    pub(crate) fn type_at(&self, idx: usize) -> Option<TypeId>
+    {
+        self.param_at(idx)?
+            .type_id
+    }
+    
+    /// Checks if any parameter, including the variadic tail, has constraints.
+    #[inline(always)]
+    pub(crate) fn has_constraints(&self) -> bool
+    {
+        self.params
+            .iter()
+            .chain(&self.variadic)
+            .any(|p| !p.constraints.is_empty())
+    }
+    
+    /// Checks if the argument count fits the signature.
+    #[inline(always)]
+    pub(crate) fn accepts_len(&self, len: usize) -> bool
+    {
+        len == self.params.len()
+        || (len > self.params.len() && self.is_variadic())
+    }
+    
+    /// Scores a set of arguments against the signature.
+    ///
+    /// Lower scores are better matches. Besides the match kind, this counts the
+    /// coerced arguments, and prefers signatures without a variadic tail.
+    /// Arguments violating a constraint do not match.
+    pub(crate) fn score(&self, args: &[Argument<'_>]) -> Option<(MatchKind, usize, bool)>
+    {
+        if !self.accepts_len(args.len())
+        {
+            return None;
+        }
+        
+        let mut coerced = 0;
+        
+        for (idx, arg) in args.iter().enumerate()
+        {
+            let param = self.param_at(idx)?;
+            
+            let exact = param.check(arg, self.coercion)?;
+            
+            if !param.satisfies(arg, exact, self.coercion)
+            {
+                return None;
+            }
+            
+            if !exact
+            {
+                coerced += 1;
+            }
+        }
+        
+        let kind =
+        if args.len() > self.params.len() { MatchKind::Variadic }
+        else if coerced != 0 { MatchKind::Coerced }
+        else { MatchKind::Exact };
+        
+        Some((kind, coerced, self.is_variadic()))
+    }
+    
+    /// Checks how well a set of arguments matches the signature.
+    ///
+    /// Returns None if the arguments do not match, including when one of them
+    /// violates a constraint. Refer to Signature::validate for the reasons.
+    #[inline(always)]
+    pub fn match_kind(&self, args: &[Argument<'_>]) -> Option<MatchKind>
+    {
+        self.score(args)
+            .map(|(kind, ..)| kind)
+    }
+    
+    /// Coerces each argument into its parameter's type.
+    ///
+    /// Arguments that already have the right type are borrowed, while the rest
+    /// are replaced by owned, coerced values.
+    ///
+    /// Returns None if the arguments do not match, or if a coerced argument
+    /// violates a constraint.
+    pub fn coerce_args<'b>(&self, args: &'b [Argument<'_>]) -> Option<Arguments<'b>>
+    {
+        if !self.accepts_len(args.len())
+        {
+            return None;
+        }
+        
+        let mut output = Vec::with_capacity(args.len());
+        
+        for (idx, arg) in args.iter().enumerate()
+        {
+            let param = self.param_at(idx)?;
+            
+            let coerced =
+            if param.check(arg, self.coercion)? { arg.as_ref() }
+            else { Argument::from((param.coerce)(arg, self.coercion)?) };
+            
+            if !param.constraints.iter().all(|c| c.check(&coerced))
+            {
+                return None;
+            }
+            
+            output.push(coerced);
+        }
+        
+        Arguments::from_args(output).ok()
+    }
+    
+    /// Checks a set of arguments against the signature, including its constraints.
+    ///
+    /// Types are checked as in Signature::match_kind, so arguments which can be
+    /// coerced are accepted. Constraints are then checked on the coerced arguments.
+    ///
+    /// # Return values
+    /// Ok(()): The arguments match the signature.
+    /// Err(SignatureError::Arity): The argument count does not fit the signature.
+    /// Err(SignatureError::Violations): Every type mismatch and constraint violation, in order.
+    pub fn validate(&self, args: &[Argument<'_>]) -> Result<(), SignatureError>
+    {
+        if !self.accepts_len(args.len())
+        {
+            return Err(SignatureError::Arity
+            {
+                expected: self.params.len(),
+                variadic: self.is_variadic(),
+                found: args.len()
+            });
+        }
+        
+        let mut violations = Vec::new();
+        
+        for (position, arg) in args.iter().enumerate()
+        {
+            let Some(param) = self.param_at(position)
+            else { continue };
+            
+            let name = param.name.clone();
+            
+            // Constraints see the argument after it is coerced into the parameter's type.
+            let coerced =
+            match param.check(arg, self.coercion)
+            {
+                Some(true) => None,
+                Some(false) => (param.coerce)(arg, self.coercion).map(Argument::from),
+                None =>
+                {
+                    violations.push(Violation
+                    {
+                        position,
+                        name,
+                        kind: ViolationKind::Type { expected: param.type_name }
+                    });
+                    
+                    continue;
+                }
+            };
+            
+            let value = coerced.as_ref().unwrap_or(arg);
+            
+            for constraint in param.constraints.iter().filter(|c| !c.check(value))
+            {
+                violations.push(Violation
+                {
+                    position,
+                    name: name.clone(),
+                    kind: ViolationKind::Constraint { description: constraint.description().into() }
+                });
+            }
+        }
+        
+        if violations.is_empty()
+        {
+            Ok(())
+        }
+        else { Err(SignatureError::Violations(violations)) }
+    }
+    
+    /// Describes each parameter along with its constraints, one per line.
+    ///
+    /// This is meant for generated help output, as in:
+    ///
+    /// ```text
+    /// count: i64 (in 1..=10)
+    /// names: String... (not empty)
+    /// ```
+    pub fn describe(&self) -> String
+    {
+        let mut output = String::new();
+        
+        for (param, tail) in self.params.iter().map(|p| (p, false))
+                                 .chain(self.variadic.iter().map(|p| (p, true)))
+        {
+            if !output.is_empty()
+            {
+                output.push('\n');
+            }
+            
+            // Writing into a String does not fail.
+            let _ = param.write_to(&mut output);
+            
+            if tail
+            {
+                output.push_str("...");
+            }
+            
+            for (idx, constraint) in param.constraints.iter().enumerate()
+            {
+                output.push_str(if idx == 0 { " (" } else { ", " });
+                output.push_str(constraint.description());
+            }
+            
+            if !param.constraints.is_empty()
+            {
+                output.push(')');
+            }
+        }
+        
+        output
+    }
+}
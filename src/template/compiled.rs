@@ -0,0 +1,193 @@
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    string::String,
+    vec::Vec
+};
+
+use crate::KeywordArguments;
+
+use super::{FormatSpec, TemplateError};
+
+/// A single piece of a compiled template.
+#[derive(Clone, Debug)]
+enum Segment
+{
+    /// Text that gets copied as is, with escaped braces already resolved.
+    Literal(Box<str>),
+    /// A named placeholder along with its parsed spec.
+    Placeholder
+    {
+        name: Box<str>,
+        spec: FormatSpec
+    }
+}
+
+/// A string template with named placeholders.
+///
+/// Placeholders are written as `{name}` or `{name:spec}`, where spec follows
+/// the syntax described in [FormatSpec]. Braces are escaped by doubling them,
+/// as in `{{` and `}}`.
+///
+/// The source is parsed once by [Template::compile], after which the template can
+/// be rendered against any amount of [KeywordArguments].
+///
+/// [FormatSpec]: FormatSpec
+/// [Template::compile]: Template::compile
+/// [KeywordArguments]: KeywordArguments
+#[derive(Clone, Debug)]
+pub struct Template
+{
+    /// The parsed pieces, in order.
+    segments: Box<[Segment]>
+}
+
+impl Template
+{
+    /// Parses a template source.
+    ///
+    /// # Return values
+    /// Ok(Self): The source is well-formed.
+    /// Err(e): The source is malformed. Refer to TemplateError for the error kinds.
+    pub fn compile(source: &str) -> Result<Self, TemplateError>
+    {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        
+        let mut rest = source;
+        
+        while let Some(idx) = rest.find(['{', '}'])
+        {
+            let position = source.len() - rest.len() + idx;
+            
+            literal.push_str(&rest[..idx]);
+            
+            let brace = rest.as_bytes()[idx];
+            let after = &rest[idx + 1..];
+            
+            // Doubled braces are escapes.
+            if after.as_bytes().first() == Some(&brace)
+            {
+                literal.push(brace as char);
+                rest = &after[1..];
+                continue;
+            }
+            
+            if brace == b'}'
+            {
+                return Err(TemplateError::UnmatchedBrace { position });
+            }
+            
+            let Some(end) = after.find(['{', '}'])
+            else
+            {
+                return Err(TemplateError::UnclosedPlaceholder { position });
+            };
+            
+            if after.as_bytes()[end] == b'{'
+            {
+                return Err(TemplateError::UnclosedPlaceholder { position });
+            }
+            
+            let (name, spec) =
+            match after[..end].split_once(':')
+            {
+                Some((name, spec)) =>
+                {
+                    let spec =
+                    FormatSpec::parse(spec)
+                        .ok_or(TemplateError::InvalidSpec { position })?;
+                    
+                    (name.trim(), spec)
+                }
+                None => (after[..end].trim(), FormatSpec::default())
+            };
+            
+            if name.is_empty()
+            {
+                return Err(TemplateError::EmptyName { position });
+            }
+            
+            if !literal.is_empty()
+            {
+                segments.push(Segment::Literal(core::mem::take(&mut literal).into_boxed_str()));
+            }
+            
+            segments.push(Segment::Placeholder { name: name.into(), spec });
+            
+            rest = &after[end + 1..];
+        }
+        
+        literal.push_str(rest);
+        
+        if !literal.is_empty()
+        {
+            segments.push(Segment::Literal(literal.into_boxed_str()));
+        }
+        
+        Ok(Self
+        {
+            segments: segments.into_boxed_slice()
+        })
+    }
+    
+    /// Iterates over the name of each placeholder, in order.
+    ///
+    /// Names that are used more than once are repeated.
+    #[inline(always)]
+    pub fn placeholders(&self) -> impl Iterator<Item = &str>
+    {
+        self.segments
+            .iter()
+            .filter_map(|s|
+            match s
+            {
+                Segment::Placeholder { name, .. } => Some(&**name),
+                Segment::Literal(_) => None
+            })
+    }
+    
+    /// Renders the template against a set of keyword arguments.
+    ///
+    /// # Return values
+    /// Ok(s): Each placeholder was found and formatted.
+    /// Err(e): Either a placeholder's name is missing from kwargs,
+    /// or its argument cannot be formatted with the placeholder's spec.
+    pub fn render(&self, kwargs: &KeywordArguments<'_>) -> Result<String, TemplateError>
+    {
+        let mut output = String::new();
+        
+        self.render_into(kwargs, &mut output)?;
+        
+        Ok(output)
+    }
+    
+    /// Renders the template, appending the result to output.
+    ///
+    /// On failure, output may contain a partially rendered template.
+    pub fn render_into(&self, kwargs: &KeywordArguments<'_>, output: &mut String)
+    -> Result<(), TemplateError>
+    {
+        for segment in self.segments.iter()
+        {
+            match segment
+            {
+                Segment::Literal(l) => output.push_str(l),
+                Segment::Placeholder { name, spec } =>
+                {
+                    let arg =
+                    kwargs.get(name)
+                          .ok_or_else(|| TemplateError::MissingKey { name: name.clone() })?;
+                    
+                    let formatted =
+                    spec.format(&**arg)
+                        .ok_or_else(|| TemplateError::UnsupportedType { name: name.clone() })?;
+                    
+                    output.push_str(&formatted);
+                }
+            }
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(no_std)]
+use alloc::boxed::Box;
+
+#[cfg(no_std)]
+use core::{error, fmt};
+
+#[cfg(not(no_std))]
+use std::{error, fmt};
+
+/// An error raised while compiling or rendering a Template.
+///
+/// Positions are byte offsets into the template source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateError
+{
+    /// A placeholder was opened, but never closed.
+    UnclosedPlaceholder { position: usize },
+    /// A closing brace was found outside of a placeholder without being escaped.
+    UnmatchedBrace { position: usize },
+    /// A placeholder does not have a name.
+    EmptyName { position: usize },
+    /// The format spec of a placeholder could not be parsed.
+    InvalidSpec { position: usize },
+    /// The keyword arguments do not contain the placeholder's name.
+    MissingKey { name: Box<str> },
+    /// The argument's type cannot be formatted with the placeholder's spec.
+    UnsupportedType { name: Box<str> }
+}
+
+impl fmt::Display for TemplateError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::UnclosedPlaceholder { position } =>
+            write!(f, "unclosed placeholder at {position}"),
+            Self::UnmatchedBrace { position } =>
+            write!(f, "unmatched '}}' at {position}"),
+            Self::EmptyName { position } =>
+            write!(f, "placeholder without a name at {position}"),
+            Self::InvalidSpec { position } =>
+            write!(f, "invalid format spec at {position}"),
+            Self::MissingKey { name } =>
+            write!(f, "missing keyword argument '{name}'"),
+            Self::UnsupportedType { name } =>
+            write!(f, "keyword argument '{name}' cannot be formatted with its spec")
+        }
+    }
+}
+
+impl error::Error for TemplateError {}
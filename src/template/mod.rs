@@ -0,0 +1,7 @@
+mod compiled;
+mod error;
+mod spec;
+
+pub use compiled::Template;
+pub use error::TemplateError;
+pub use spec::{Alignment, FormatKind, FormatSpec};
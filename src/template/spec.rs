@@ -0,0 +1,396 @@
+#[cfg(no_std)]
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    format,
+    string::{String, ToString}
+};
+
+#[cfg(no_std)]
+use core::any::Any;
+
+#[cfg(not(no_std))]
+use std::{
+    any::Any,
+    borrow::Cow
+};
+
+/// The alignment of a formatted value within its width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment
+{
+    /// `<`
+    Left,
+    /// `^`
+    Center,
+    /// `>`
+    Right
+}
+
+/// The presentation type of a formatted value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FormatKind
+{
+    /// No type, which uses Display.
+    #[default]
+    Display,
+    /// `?`
+    Debug,
+    /// `x`
+    LowerHex,
+    /// `X`
+    UpperHex,
+    /// `o`
+    Octal,
+    /// `b`
+    Binary,
+    /// `e`
+    LowerExp,
+    /// `E`
+    UpperExp
+}
+
+/// A parsed format spec.
+///
+/// The syntax follows the one used by [std::fmt]:
+///
+/// `[[fill]align][sign]['#']['0'][width]['.' precision][type]`
+///
+/// Width and precision must be literal numbers, and the sign may only be `+` or `-`.
+///
+/// [std::fmt]: https://doc.rust-lang.org/std/fmt/index.html#syntax
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatSpec
+{
+    /// The character used for padding.
+    pub fill: char,
+    /// The alignment. When none is given, numbers align to the right and
+    /// everything else aligns to the left.
+    pub align: Option<Alignment>,
+    /// Whether or not non-negative numbers, other than NaN and infinity, are prefixed with `+`.
+    pub sign_plus: bool,
+    /// The alternate flag, `#`.
+    pub alternate: bool,
+    /// Pads numbers with zeros after their sign and prefix.
+    /// NaN and infinite floats are padded with the fill instead.
+    pub zero_pad: bool,
+    /// The minimum width, in characters.
+    pub width: Option<usize>,
+    /// The precision for floats, or the maximum length for strings.
+    pub precision: Option<usize>,
+    /// The presentation type.
+    pub kind: FormatKind
+}
+
+impl Default for FormatSpec
+{
+    #[inline(always)]
+    fn default() -> Self
+    {
+        Self
+        {
+            fill: ' ',
+            align: None,
+            sign_plus: false,
+            alternate: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            kind: FormatKind::Display
+        }
+    }
+}
+
+/// Splits the leading digits from a spec, parsing them.
+///
+/// Returns None as the number if there are no leading digits,
+/// and Err(()) if the number overflows.
+#[inline(always)]
+fn split_number(spec: &str) -> Result<(Option<usize>, &str), ()>
+{
+    let end =
+    spec.find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(spec.len());
+    
+    if end == 0
+    {
+        return Ok((None, spec));
+    }
+    
+    match spec[..end].parse()
+    {
+        Ok(n) => Ok((Some(n), &spec[end..])),
+        Err(_) => Err(())
+    }
+}
+
+#[inline(always)]
+fn alignment(c: char) -> Option<Alignment>
+{
+    match c
+    {
+        '<' => Some(Alignment::Left),
+        '^' => Some(Alignment::Center),
+        '>' => Some(Alignment::Right),
+        _ => None
+    }
+}
+
+/// Formats a value into its unpadded body.
+///
+/// The returned triple marks the body as numeric and as finite, followed by the body.
+/// Only NaN and infinite floats are not finite.
+macro_rules! format_body
+{
+    ($spec:expr, $value:expr;
+     ints: [$($int:ty),*];
+     floats: [$($float:ty),*];
+     texts: [$($text:ty),*]) =>
+    {
+        'body:
+        {
+            $(
+                if let Some(v) = $value.downcast_ref::<$int>()
+                {
+                    let body =
+                    match $spec.kind
+                    {
+                        FormatKind::Display => v.to_string(),
+                        FormatKind::Debug => format!("{v:?}"),
+                        FormatKind::LowerHex => format!("{v:x}"),
+                        FormatKind::UpperHex => format!("{v:X}"),
+                        FormatKind::Octal => format!("{v:o}"),
+                        FormatKind::Binary => format!("{v:b}"),
+                        FormatKind::LowerExp => format!("{v:e}"),
+                        FormatKind::UpperExp => format!("{v:E}")
+                    };
+                    
+                    break 'body Some((true, true, body));
+                }
+            )*
+            $(
+                if let Some(v) = $value.downcast_ref::<$float>()
+                {
+                    let body =
+                    match ($spec.kind, $spec.precision)
+                    {
+                        (FormatKind::Display, Some(p)) => format!("{v:.p$}"),
+                        (FormatKind::Display, None) => v.to_string(),
+                        (FormatKind::Debug, Some(p)) => format!("{v:.p$?}"),
+                        (FormatKind::Debug, None) => format!("{v:?}"),
+                        (FormatKind::LowerExp, Some(p)) => format!("{v:.p$e}"),
+                        (FormatKind::LowerExp, None) => format!("{v:e}"),
+                        (FormatKind::UpperExp, Some(p)) => format!("{v:.p$E}"),
+                        (FormatKind::UpperExp, None) => format!("{v:E}"),
+                        _ => break 'body None
+                    };
+                    
+                    break 'body Some((true, v.is_finite(), body));
+                }
+            )*
+            $(
+                if let Some(v) = $value.downcast_ref::<$text>()
+                {
+                    let body =
+                    match ($spec.kind, $spec.precision)
+                    {
+                        (FormatKind::Display, Some(p)) => format!("{v:.p$}"),
+                        (FormatKind::Display, None) => v.to_string(),
+                        (FormatKind::Debug, _) => format!("{v:?}"),
+                        _ => break 'body None
+                    };
+                    
+                    break 'body Some((false, true, body));
+                }
+            )*
+            
+            None
+        }
+    };
+}
+
+impl FormatSpec
+{
+    /// Parses a format spec, which is the part after the colon in `{name:spec}`.
+    ///
+    /// Returns None if the spec is malformed.
+    pub fn parse(spec: &str) -> Option<Self>
+    {
+        let mut output = Self::default();
+        
+        let mut chars = spec.chars();
+        
+        let rest =
+        match (chars.next(), chars.next())
+        {
+            (Some(fill), Some(c)) if alignment(c).is_some() =>
+            {
+                output.fill = fill;
+                output.align = alignment(c);
+                chars.as_str()
+            }
+            (Some(c), _) if alignment(c).is_some() =>
+            {
+                output.align = alignment(c);
+                &spec[c.len_utf8()..]
+            }
+            _ => spec
+        };
+        
+        let rest =
+        match rest.as_bytes().first()
+        {
+            Some(b'+') =>
+            {
+                output.sign_plus = true;
+                &rest[1..]
+            }
+            Some(b'-') => &rest[1..],
+            _ => rest
+        };
+        
+        let rest =
+        match rest.strip_prefix('#')
+        {
+            Some(r) =>
+            {
+                output.alternate = true;
+                r
+            }
+            None => rest
+        };
+        
+        let rest =
+        match rest.strip_prefix('0')
+        {
+            // `0.N` is a zero width followed by a precision, rather than a flag.
+            Some(r) if !r.starts_with('.') =>
+            {
+                output.zero_pad = true;
+                r
+            }
+            _ => rest
+        };
+        
+        let (width, rest) = split_number(rest).ok()?;
+        output.width = width;
+        
+        let rest =
+        match rest.strip_prefix('.')
+        {
+            Some(r) =>
+            {
+                let (precision, r) = split_number(r).ok()?;
+                output.precision = Some(precision?);
+                r
+            }
+            None => rest
+        };
+        
+        output.kind =
+        match rest
+        {
+            "" => FormatKind::Display,
+            "?" => FormatKind::Debug,
+            "x" => FormatKind::LowerHex,
+            "X" => FormatKind::UpperHex,
+            "o" => FormatKind::Octal,
+            "b" => FormatKind::Binary,
+            "e" => FormatKind::LowerExp,
+            "E" => FormatKind::UpperExp,
+            _ => return None
+        };
+        
+        Some(output)
+    }
+    
+    /// Formats a value according to the spec, returning the result.
+    ///
+    /// Supported values are the integer and float primitives, bool, char, String,
+    /// `&'static str`, `Box<str>` and `Cow<'static, str>`.
+    ///
+    /// Returns None if the value's type is not supported, or if the
+    /// spec's type cannot be used on it (such as hexadecimal floats).
+    pub fn format(&self, value: &dyn Any) -> Option<String>
+    {
+        let (numeric, finite, body) =
+        format_body!(self, value;
+                     ints: [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize];
+                     floats: [f32, f64];
+                     texts: [bool, char, String, &'static str, Box<str>, Cow<'static, str>])?;
+        
+        let mut output = String::with_capacity(body.len());
+        
+        let digits =
+        if numeric
+        {
+            let digits =
+            match body.strip_prefix('-')
+            {
+                Some(d) =>
+                {
+                    output.push('-');
+                    d
+                }
+                None if self.sign_plus && finite =>
+                {
+                    output.push('+');
+                    &body[..]
+                }
+                None => &body[..]
+            };
+            
+            if self.alternate
+            {
+                match self.kind
+                {
+                    FormatKind::LowerHex | FormatKind::UpperHex => output.push_str("0x"),
+                    FormatKind::Octal => output.push_str("0o"),
+                    FormatKind::Binary => output.push_str("0b"),
+                    _ => ()
+                }
+            }
+            
+            digits
+        }
+        else { &body[..] };
+        
+        let length = output.chars().count() + digits.chars().count();
+        let padding = self.width.unwrap_or(0).saturating_sub(length);
+        
+        // NaN and infinite floats are padded with the fill instead.
+        if numeric && finite && self.zero_pad
+        {
+            output.extend(core::iter::repeat_n('0', padding));
+            output.push_str(digits);
+            
+            return Some(output);
+        }
+        
+        output.push_str(digits);
+        
+        let align =
+        match (self.align, numeric)
+        {
+            (Some(a), _) => a,
+            (None, true) => Alignment::Right,
+            (None, false) => Alignment::Left
+        };
+        
+        let (before, after) =
+        match align
+        {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0)
+        };
+        
+        let mut padded = String::with_capacity(output.len() + padding * self.fill.len_utf8());
+        
+        padded.extend(core::iter::repeat_n(self.fill, before));
+        padded.push_str(&output);
+        padded.extend(core::iter::repeat_n(self.fill, after));
+        
+        Some(padded)
+    }
+}
@@ -7,7 +7,8 @@ use core::ops::Deref;
 #[cfg(no_std)]
 use alloc::{
     boxed::Box,
-    vec
+    vec,
+    vec::Vec
 };
 
 fn test_borrowed<T>(item: &T)
@@ -119,3 +120,22 @@ fn test_owned_alloc()
     let current = vec!(1_u8; 100);
     test_owned(current);
 }
+
+#[test]
+fn test_to_mut()
+{
+    let mut owned = Argument::new_owned(1_i32);
+    
+    *owned.to_mut().downcast_mut::<i32>().unwrap() += 1;
+    
+    assert_eq!(owned.downcast_ref::<i32>(), Some(&2));
+    
+    let current = vec!(1_u8; 100);
+    let mut borrowed = Argument::new_borrowed(&current);
+    
+    borrowed.to_mut().downcast_mut::<Vec<u8>>().unwrap().push(2);
+    
+    assert!(borrowed.is_owned());
+    assert_eq!(borrowed.downcast_ref::<Vec<u8>>().map(Vec::len), Some(101));
+    assert_eq!(current.len(), 100);
+}
@@ -0,0 +1,108 @@
+use crate::{ArgvError, ArgumentsBuilder, CommandSpec, FlagStyle, KeywordArguments, ToArgv};
+
+use std::{
+    ffi::OsString,
+    path::PathBuf
+};
+
+#[derive(Clone)]
+struct Level(u8);
+
+impl ToArgv for Level
+{
+    fn to_argv(&self) -> OsString
+    {
+        OsString::from(format!("L{}", self.0))
+    }
+}
+
+fn spec() -> CommandSpec
+{
+    CommandSpec::new("tool").positional::<i32>("count", "").unwrap()
+                            .positional::<PathBuf>("path", "").unwrap()
+                            .flag::<f64>("rate", Some('r'), "").unwrap()
+                            .flag::<String>("name", None, "").unwrap()
+                            .switch("force", Some('f'), "").unwrap()
+                            .switch("dry", None, "").unwrap()
+}
+
+#[test]
+fn test_to_argv()
+{
+    let args =
+    ArgumentsBuilder::new().arg(-3_i32).arg(PathBuf::from("a/b")).arg_str("text").arg(true).build();
+    
+    assert_eq!(args.to_argv().unwrap(), ["-3", "a/b", "text", "true"]);
+    
+    let args = ArgumentsBuilder::new().arg(1_u8).arg(Level(2)).build();
+    
+    assert_eq!(args.to_argv(), Err(ArgvError::Unsupported { position: 1 }));
+    assert_eq!(args.to_argv_with(|v| v.downcast_ref::<Level>().map(ToArgv::to_argv)).unwrap(), ["1", "L2"]);
+}
+
+fn round_trip(flags: &KeywordArguments<'_>, style: FlagStyle)
+{
+    let positional = ArgumentsBuilder::new().arg(-3_i32).arg(PathBuf::from("out dir")).build();
+    
+    let command = flags.to_command("tool", &positional, style).unwrap();
+    
+    let argv : Vec<&str> =
+    command.get_args()
+           .map(|a| a.to_str().unwrap())
+           .collect();
+    
+    let parsed = spec().parse(&argv).unwrap();
+    
+    assert_eq!(parsed.positional[0].downcast_ref::<i32>(), Some(&-3));
+    assert_eq!(parsed.positional[1].downcast_ref::<PathBuf>(), Some(&PathBuf::from("out dir")));
+    assert_eq!(parsed.flags.get("rate").and_then(|a| a.downcast_ref::<f64>()), Some(&2.5));
+    assert_eq!(parsed.flags.get("force").and_then(|a| a.downcast_ref::<bool>()), Some(&true));
+    assert_eq!(parsed.flags.get("dry").and_then(|a| a.downcast_ref::<bool>()), Some(&false));
+}
+
+#[test]
+fn test_round_trip()
+{
+    let mut flags = KeywordArguments::new();
+    
+    let _ = flags.insert_owned("rate", 2.5_f64);
+    let _ = flags.insert_owned("name", String::from("x y"));
+    let _ = flags.insert_owned("force", true);
+    let _ = flags.insert_owned("dry", false);
+    
+    assert_eq!(flags.to_flags(FlagStyle::LongEquals).unwrap(), ["--rate=2.5", "--name=x y", "--force"]);
+    assert_eq!(flags.to_flags(FlagStyle::LongSeparate).unwrap(), ["--rate", "2.5", "--name", "x y", "--force"]);
+    
+    round_trip(&flags, FlagStyle::LongEquals);
+    round_trip(&flags, FlagStyle::LongSeparate);
+    
+    let mut flags = KeywordArguments::new();
+    
+    let _ = flags.insert_owned("r", 2.5_f64);
+    let _ = flags.insert_owned("f", true);
+    
+    assert_eq!(flags.to_flags(FlagStyle::Short).unwrap(), ["-r", "2.5", "-f"]);
+    
+    round_trip(&flags, FlagStyle::Short);
+}
+
+#[test]
+fn test_fallback()
+{
+    let render = |v: &dyn std::any::Any| v.downcast_ref::<Level>().map(ToArgv::to_argv);
+    
+    let mut flags = KeywordArguments::new();
+    
+    let _ = flags.insert_owned("level", Level(3));
+    
+    assert_eq!(flags.to_flags(FlagStyle::LongEquals), Err(ArgvError::UnsupportedKey { key: "level".into() }));
+    assert_eq!(flags.to_flags_with(FlagStyle::LongSeparate, render).unwrap(), ["--level", "L3"]);
+    
+    let positional = ArgumentsBuilder::new().arg(Level(1)).build();
+    
+    assert!(flags.to_command("tool", &positional, FlagStyle::LongEquals).is_err());
+    
+    let command = flags.to_command_with("tool", &positional, FlagStyle::LongEquals, render).unwrap();
+    
+    assert_eq!(command.get_args().collect::<Vec<_>>(), ["--level=L3", "L1"]);
+}
@@ -0,0 +1,106 @@
+use crate::{Arguments, ArgumentsBatch, ArgumentsBuilder, BatchError, Coercion, Constraint, Signature};
+
+use core::any::TypeId;
+
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec,
+    vec::Vec
+};
+
+#[test]
+fn test_columns()
+{
+    let batch =
+    ArgumentsBatch::new().with_column(vec![1_i32, 2, 3]).unwrap()
+                         .with_column(vec![String::from("a"), String::from("b"), String::from("c")]).unwrap();
+    
+    assert_eq!(batch.rows(), 3);
+    assert_eq!(batch.columns(), 2);
+    assert_eq!(batch.column::<i32>(0), Some(&[1, 2, 3][..]));
+    assert!(batch.column::<u8>(0).is_none());
+    assert!(batch.column::<i32>(2).is_none());
+    
+    assert!(batch.validate(&[TypeId::of::<i32>(), TypeId::of::<String>()]).is_ok());
+    assert_eq!(batch.validate(&[TypeId::of::<i32>(), TypeId::of::<u8>()]),
+               Err(BatchError::ColumnType { row: None, column: 1 }));
+    assert_eq!(batch.validate(&[TypeId::of::<i32>()]),
+               Err(BatchError::ColumnCount { expected: 1, found: 2 }));
+    
+    assert!(batch.validate_signature(&Signature::new().param::<i32>().param::<String>()).is_ok());
+    assert!(batch.validate_signature(&Signature::new().param::<i32>().variadic_any()).is_ok());
+    assert_eq!(batch.validate_signature(&Signature::new().param::<i32>().param::<u8>()),
+               Err(BatchError::ColumnType { row: None, column: 1 }));
+    assert_eq!(batch.validate_signature(&Signature::new().param::<i32>()),
+               Err(BatchError::ColumnCount { expected: 1, found: 2 }));
+    
+    // Constraints and coercions are checked per row.
+    let sig = Signature::new().param::<i64>().constrain(Constraint::range(1_i64..3)).param_any();
+    
+    assert_eq!(batch.validate_signature(&sig), Err(BatchError::ColumnType { row: None, column: 0 }));
+    assert_eq!(batch.validate_signature(&sig.with_coercion(Coercion::Lossless)),
+               Err(BatchError::Constraint { row: 2, column: 0 }));
+    
+    let mut batch = batch;
+    
+    assert_eq!(batch.push_column(vec![1_u8]), Err(vec![1_u8]));
+}
+
+#[test]
+fn test_rows()
+{
+    let batch =
+    ArgumentsBatch::new().with_column(vec![1_i32, 2, 3]).unwrap()
+                         .with_column(vec![10_i64, 20, 30]).unwrap();
+    
+    let row = batch.row(1).unwrap();
+    
+    assert!(row.iter().all(|a| a.is_borrowed()));
+    assert_eq!(row[0].downcast_ref::<i32>(), Some(&2));
+    assert!(batch.row(3).is_none());
+    
+    let sums =
+    batch.map_rows(|row| *row[0].downcast_ref::<i32>().unwrap() as i64
+                       + *row[1].downcast_ref::<i64>().unwrap());
+    
+    assert_eq!(sums, vec![11, 22, 33]);
+}
+
+#[test]
+fn test_from_rows()
+{
+    let text = String::from("borrowed");
+    
+    let rows = vec![ArgumentsBuilder::new().arg(1_u8).arg_str("static").build(),
+                    ArgumentsBuilder::new().arg(2_u8).arg_str(&text).build()];
+    
+    let mut batch = ArgumentsBatch::from_rows(&rows).unwrap();
+    
+    assert_eq!(batch.rows(), 2);
+    assert!(batch.validate(&[TypeId::of::<u8>(), TypeId::of::<String>()]).is_ok());
+    assert_eq!(batch.row(1).unwrap()[1].downcast_str(), Some("borrowed"));
+    
+    // Columns built from rows become contiguous once their type is given.
+    assert!(batch.column::<u8>(0).is_none());
+    assert!(batch.column_mut::<u16>(0).is_none());
+    assert_eq!(batch.column_mut::<u8>(0), Some(&mut [1, 2][..]));
+    assert_eq!(batch.column::<u8>(0), Some(&[1, 2][..]));
+    assert_eq!(batch.column_mut::<String>(1).unwrap()[1], "borrowed");
+    
+    let owned : Vec<Arguments<'static>> = batch.into_rows();
+    
+    assert_eq!(owned[1][1].downcast_ref::<String>().map(String::as_str), Some("borrowed"));
+    
+    let rows = vec![ArgumentsBuilder::new().arg(1_u8).build(),
+                    ArgumentsBuilder::new().arg(1_u16).build()];
+    
+    assert_eq!(ArgumentsBatch::from_rows(&rows).unwrap_err(),
+               BatchError::ColumnType { row: Some(1), column: 0 });
+    
+    let rows = vec![ArgumentsBuilder::new().arg(1_u8).build(),
+                    ArgumentsBuilder::new().build()];
+    
+    assert!(matches!(ArgumentsBatch::from_rows(&rows),
+                     Err(BatchError::RowLength { row: 1, expected: 1, found: 0 })));
+}
@@ -0,0 +1,84 @@
+use crate::{ArgumentsBuilder, BindError, KeywordArguments, ParamSpec, ParamSpecError};
+
+#[cfg(no_std)]
+use alloc::{
+    boxed::Box,
+    string::ToString,
+    vec,
+    vec::Vec
+};
+
+/// def f(a, b=2, *args, c, d=4, **kwargs)
+fn spec() -> ParamSpec
+{
+    ParamSpec::new().param("a").unwrap()
+                    .param_default("b", 2_i32).unwrap()
+                    .rest("args").unwrap()
+                    .keyword("c").unwrap()
+                    .keyword_default("d", 4_i32).unwrap()
+                    .extra("kwargs").unwrap()
+}
+
+#[test]
+fn test_bind()
+{
+    let positional = ArgumentsBuilder::new().arg(1_i32).arg(20_i32).arg(30_i32).arg(40_i32).build();
+    
+    let mut keywords = KeywordArguments::new();
+    
+    let _ = keywords.insert_owned("c", 3_i32);
+    let _ = keywords.insert_owned("z", 26_i32);
+    
+    let frame = spec().bind(positional, keywords).unwrap();
+    
+    let values : Vec<(&str, i32)> =
+    frame.iter()
+         .map(|(n, a)| (n, *a.downcast_ref::<i32>().unwrap()))
+         .collect();
+    
+    assert_eq!(values, vec![("a", 1), ("b", 20), ("c", 3), ("d", 4)]);
+    assert_eq!(frame.rest().iter_of::<i32>().copied().collect::<Vec<_>>(), vec![30, 40]);
+    assert_eq!(frame.extra().get("z").and_then(|a| a.downcast_ref::<i32>()), Some(&26));
+}
+
+#[test]
+fn test_bind_errors()
+{
+    let strict = ParamSpec::new().param("a").unwrap().param("b").unwrap();
+    
+    let positional = ArgumentsBuilder::new().arg(1_i32).arg(2_i32).arg(3_i32).build();
+    
+    assert_eq!(strict.bind(positional, KeywordArguments::new()).unwrap_err(),
+               BindError::TooManyPositional { expected: 2, found: 3 });
+    
+    let positional = ArgumentsBuilder::new().arg(1_i32).build();
+    let mut keywords = KeywordArguments::new();
+    
+    let _ = keywords.insert_owned("a", 1_i32);
+    
+    assert_eq!(strict.bind(positional, keywords).unwrap_err(),
+               BindError::MultipleValues { name: "a".into() });
+    
+    let mut keywords = KeywordArguments::new();
+    
+    let _ = keywords.insert_owned("x", 1_i32);
+    
+    assert_eq!(strict.bind(ArgumentsBuilder::new().build(), keywords).unwrap_err(),
+               BindError::UnexpectedKeyword { name: "x".into() });
+    
+    let error = spec().bind(ArgumentsBuilder::new().build(), KeywordArguments::new()).unwrap_err();
+    
+    assert_eq!(error, BindError::MissingRequired { names: vec![Box::from("a"), Box::from("c")] });
+    assert_eq!(error.to_string(), "missing 2 required arguments: 'a', 'c'");
+}
+
+#[test]
+fn test_spec_errors()
+{
+    assert_eq!(ParamSpec::new().param_default("a", 1_i32).unwrap().param("b").unwrap_err(),
+               ParamSpecError::RequiredAfterDefault { name: "b".into() });
+    assert_eq!(ParamSpec::new().param("a").unwrap().keyword("a").unwrap_err(),
+               ParamSpecError::DuplicateName { name: "a".into() });
+    assert_eq!(ParamSpec::new().rest("args").unwrap().param("a").unwrap_err(),
+               ParamSpecError::PositionalAfterRest { name: "a".into() });
+}
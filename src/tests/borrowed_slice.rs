@@ -0,0 +1,152 @@
+use crate::{Argument, ArgumentKind, ArgumentsBuilder};
+
+#[cfg(no_std)]
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec
+};
+
+#[test]
+fn test_borrowed_str()
+{
+    let source = String::from("borrowed");
+    
+    let arg = Argument::new_borrowed_str(&source[..4]);
+    
+    assert!(arg.is_borrowed());
+    assert!(!arg.is_owned());
+    assert_eq!(arg.downcast_str(), Some("borr"));
+    assert_eq!(arg.downcast_slice::<u8>(), None);
+    
+    // Clones keep borrowing, while to_owned promotes.
+    let cloned = arg.clone();
+    assert!(cloned.is_borrowed());
+    assert_eq!(cloned.downcast_str(), Some("borr"));
+    
+    let owned = arg.to_owned();
+    assert!(owned.is_owned());
+    assert_eq!(owned.downcast_ref::<String>().map(|s| &s[..]), Some("borr"));
+    
+    assert!(format!("{arg:?}").starts_with("Argument::Borrowed"));
+}
+
+#[test]
+fn test_borrowed_str_to_mut()
+{
+    let source = "text";
+    
+    let mut arg = Argument::new_borrowed_str(source);
+    
+    arg.to_mut()
+       .downcast_mut::<String>()
+       .unwrap()
+       .push('!');
+    
+    assert!(arg.is_owned());
+    assert_eq!(arg.downcast_str(), Some("text!"));
+    assert_eq!(source, "text");
+}
+
+#[test]
+fn test_borrowed_slice()
+{
+    let source = vec![1_u32, 2, 3];
+    
+    let mut arg = Argument::new_borrowed_slice(&source[1..]);
+    
+    assert_eq!(arg.downcast_slice::<u32>(), Some(&[2, 3][..]));
+    
+    {
+        let view = arg.as_ref();
+        
+        assert!(view.is_borrowed());
+        assert_eq!(view.downcast_slice::<u32>(), Some(&[2, 3][..]));
+    }
+    
+    arg.to_mut()
+       .downcast_mut::<Vec<u32>>()
+       .unwrap()
+       .push(4);
+    
+    assert_eq!(arg.downcast_slice::<u32>(), Some(&[2, 3, 4][..]));
+    assert_eq!(source, [1, 2, 3]);
+    
+    match Argument::new_borrowed_slice(&source[..1]).into_inner()
+    {
+        ArgumentKind::Owned(o) => assert_eq!(o.downcast_cloned::<Vec<u32>>(), Some(vec![1])),
+        ArgumentKind::Borrowed(_) => unreachable!()
+    }
+}
+
+#[test]
+fn test_builder()
+{
+    let text = String::from("word");
+    let numbers = [1_i8, 2];
+    
+    let mut builder = ArgumentsBuilder::new();
+    
+    assert!(builder.insert_borrowed_str(&text));
+    assert!(builder.insert_borrowed_slice(&numbers));
+    
+    let args = builder.build();
+    
+    assert_eq!(args[0].downcast_str(), Some("word"));
+    assert_eq!(args[1].downcast_slice::<i8>(), Some(&[1, 2][..]));
+}
+
+#[test]
+fn test_value_type()
+{
+    use crate::{match_arg, Arguments, Coercion, ConversionRegistry, OwnedArgument, Signature};
+    
+    #[cfg(no_std)]
+    use core::any::TypeId;
+    
+    #[cfg(not(no_std))]
+    use std::any::TypeId;
+    
+    let arg = Argument::new_borrowed_str("12");
+    
+    assert_eq!(arg.value_type_id(), TypeId::of::<String>());
+    assert_eq!(arg.downcast_cloned::<String>(), Some(String::from("12")));
+    assert_eq!(arg.coerce::<String>(), Some(String::from("12")));
+    
+    let mut registry = ConversionRegistry::new();
+    registry.register::<String, u32>(|v| v.downcast_ref::<String>()?.parse::<u32>().ok().map(OwnedArgument::new));
+    
+    assert_eq!(arg.convert::<u32>(&registry).map(|c| c.value), Some(12));
+    
+    let len =
+    match_arg!(arg.clone(), {
+        String => |s| s.len(),
+        _ => 0
+    });
+    
+    assert_eq!(len, 2);
+    assert_eq!(arg.downcast_owned::<String>().ok(), Some(String::from("12")));
+    
+    let numbers = [1_u8, 2];
+    
+    let mut args = Arguments::from_args(vec![
+        Argument::new_borrowed_slice(&numbers),
+        Argument::new_owned(vec![3_u8])
+    ]).unwrap();
+    
+    // Type queries only match what can be read by reference, while signatures promote.
+    assert_eq!(args.count_of::<Vec<u8>>(), 1);
+    assert_eq!(args.position_of::<Vec<u8>>(), Some(1));
+    
+    let signature = Signature::new().param::<Vec<u8>>().param::<Vec<u8>>().with_coercion(Coercion::Exact);
+    
+    assert!(signature.match_kind(&args).is_some());
+    
+    for v in args.iter_of_mut::<Vec<u8>>()
+    {
+        v.push(0);
+    }
+    
+    assert_eq!(args.into_iter_of::<Vec<u8>>().collect::<Vec<_>>(), [vec![1, 2, 0], vec![3, 0]]);
+}
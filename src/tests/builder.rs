@@ -0,0 +1,139 @@
+use crate::{Argument, ArgumentsBuilder, CapacityError, MAX_ARG_COUNT};
+
+#[cfg(no_std)]
+use alloc::vec::Vec;
+
+fn numbers(builder: &ArgumentsBuilder<'_>) -> Vec<i32>
+{
+    builder.iter()
+           .map(|a| a.downcast_cloned::<i32>().unwrap())
+           .collect()
+}
+
+fn sample() -> ArgumentsBuilder<'static>
+{
+    let mut builder = ArgumentsBuilder::new();
+    
+    for n in 0..6
+    {
+        builder.insert_owned(n).unwrap();
+    }
+    
+    builder
+}
+
+#[test]
+fn test_insert_replace_swap()
+{
+    let mut builder = sample();
+    
+    assert!(builder.insert_at(1, Argument::new_owned(10)).is_ok());
+    assert!(builder.insert_at(7, Argument::new_owned(11)).is_ok());
+    assert!(builder.insert_at(9, Argument::new_owned(12)).is_err());
+    
+    assert_eq!(numbers(&builder), [0, 10, 1, 2, 3, 4, 5, 11]);
+    
+    let previous = builder.replace(0, Argument::new_owned(20)).unwrap();
+    assert_eq!(previous.downcast_cloned::<i32>(), Some(0));
+    assert!(builder.replace(8, Argument::new_owned(0)).is_err());
+    
+    assert!(builder.swap(0, 7));
+    assert!(!builder.swap(0, 8));
+    
+    assert_eq!(numbers(&builder), [11, 10, 1, 2, 3, 4, 5, 20]);
+    
+    // Mutable access through DerefMut.
+    builder[1] = Argument::new_owned(30);
+    builder.reverse();
+    
+    assert_eq!(numbers(&builder), [20, 5, 4, 3, 2, 1, 30, 11]);
+}
+
+#[test]
+fn test_remove_ranges()
+{
+    let mut builder = sample();
+    
+    builder.retain(|a| a.downcast_cloned::<i32>().is_some_and(|n| n % 2 == 0));
+    assert_eq!(numbers(&builder), [0, 2, 4]);
+    
+    let mut builder = sample();
+    
+    let drained : Vec<_> = builder.drain(1..=2).unwrap().collect();
+    assert_eq!(drained.len(), 2);
+    assert_eq!(numbers(&builder), [0, 3, 4, 5]);
+    
+    assert!(builder.drain(3..5).is_none());
+    
+    builder.truncate(2);
+    assert_eq!(numbers(&builder), [0, 3]);
+    
+    builder.clear();
+    assert!(builder.is_empty());
+}
+
+#[test]
+fn test_insert_at_full()
+{
+    let mut builder = ArgumentsBuilder::with_capacity(MAX_ARG_COUNT);
+    
+    while builder.insert_owned(0_u8).is_ok() {}
+    
+    assert!(builder.insert_at(0, Argument::new_owned(1_u8)).is_err());
+    assert_eq!(builder.len(), MAX_ARG_COUNT);
+}
+
+#[test]
+fn test_into_builder()
+{
+    let args = sample().build();
+    
+    let pointer = args.as_ptr();
+    
+    let mut builder = args.into_builder();
+    
+    // The table is reused.
+    assert_eq!(builder.as_ptr(), pointer);
+    
+    builder.pop();
+    
+    assert_eq!(numbers(&builder), [0, 1, 2, 3, 4]);
+    assert_eq!(builder.build().len(), 5);
+}
+
+#[test]
+fn test_chaining()
+{
+    let x = 5_u64;
+    let text = "text";
+    
+    let args =
+    ArgumentsBuilder::new().arg(1_i32)
+                           .arg_ref(&x)
+                           .arg_str(text)
+                           .arg_argument(Argument::new_owned('c'))
+                           .try_build()
+                           .unwrap();
+    
+    assert_eq!(args.len(), 4);
+    assert!(args[1].is_borrowed());
+    assert_eq!(args[3].downcast_cloned::<char>(), Some('c'));
+}
+
+#[test]
+fn test_chaining_overflow()
+{
+    let mut builder = ArgumentsBuilder::new().arg(0_u8);
+    
+    for n in 1..MAX_ARG_COUNT + 3
+    {
+        builder = builder.arg(n);
+    }
+    
+    let error = builder.clone().try_build().unwrap_err();
+    
+    assert_eq!(error, CapacityError { index: MAX_ARG_COUNT, rejected: 3 });
+    
+    // build still returns whatever fit.
+    assert_eq!(builder.build().len(), MAX_ARG_COUNT);
+}
@@ -0,0 +1,99 @@
+use crate::{CliErrorKind, CommandSpec, CommandSpecError, MAX_ARG_COUNT};
+
+#[cfg(no_std)]
+use alloc::{
+    format,
+    string::String,
+    vec::Vec
+};
+
+fn spec() -> CommandSpec
+{
+    CommandSpec::new("move").about("Moves a sprite.")
+        .positional::<i64>("x", "Horizontal target").unwrap()
+        .positional::<i64>("y", "Vertical target").unwrap()
+        .variadic::<String>("tags", "Tags to attach").unwrap()
+        .flag_default::<f64>("speed", Some('s'), "Movement speed", 1.0).unwrap()
+        .flag::<String>("label", None, "Label to show").unwrap().value_name("TEXT")
+        .switch("verbose", Some('v'), "Prints each step").unwrap()
+        .switch("quiet", Some('q'), "Prints nothing").unwrap()
+}
+
+#[test]
+fn test_parse()
+{
+    let parsed = spec().parse(&["10", "-20", "--speed=2.5", "-vq", "a", "--", "--label"]).unwrap();
+    
+    assert_eq!(parsed.positional[0].downcast_ref::<i64>(), Some(&10));
+    assert_eq!(parsed.positional[1].downcast_ref::<i64>(), Some(&-20));
+    
+    let tags : Vec<&String> = parsed.positional.iter_of::<String>().collect();
+    
+    assert_eq!(tags, ["a", "--label"]);
+    assert_eq!(parsed.flags.get("speed").and_then(|a| a.downcast_ref::<f64>()), Some(&2.5));
+    assert_eq!(parsed.flags.get("verbose").and_then(|a| a.downcast_ref::<bool>()), Some(&true));
+    assert_eq!(parsed.flags.get("quiet").and_then(|a| a.downcast_ref::<bool>()), Some(&true));
+    assert!(!parsed.flags.contains_key("label"));
+    
+    let parsed = spec().parse(&["1", "2", "-s", "3", "--label", "hi"]).unwrap();
+    
+    assert_eq!(parsed.flags.get("speed").and_then(|a| a.downcast_ref::<f64>()), Some(&3.0));
+    assert_eq!(parsed.flags.get("label").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("hi"));
+    assert_eq!(parsed.flags.get("verbose").and_then(|a| a.downcast_ref::<bool>()), Some(&false));
+}
+
+#[test]
+fn test_errors()
+{
+    let tokens = ["10", "abc"];
+    let error = spec().parse(&tokens).unwrap_err();
+    
+    assert_eq!(error.kind, CliErrorKind::InvalidValue { name: "y".into(), expected: "i64" });
+    assert_eq!(error.index, Some(1));
+    assert_eq!(error.render(&tokens),
+               "error: invalid value for y: expected i64 (token 1: 'abc')\n  10 abc\n     ^^^");
+    
+    let error = spec().parse(&["1", "2", "--fast"]).unwrap_err();
+    assert_eq!(error.kind, CliErrorKind::UnknownFlag { flag: "--fast".into() });
+    
+    let error = spec().parse(&["1", "2", "--speed"]).unwrap_err();
+    assert_eq!(error.kind, CliErrorKind::MissingValue { flag: "--speed".into() });
+    
+    let error = spec().parse(&["1", "--verbose=yes"]).unwrap_err();
+    assert_eq!(error.kind, CliErrorKind::UnexpectedValue { flag: "--verbose".into() });
+    
+    let error = spec().parse(&["1"]).unwrap_err();
+    assert_eq!(error.kind, CliErrorKind::MissingPositional { name: "y".into() });
+    
+    let strict = CommandSpec::new("strict").positional::<u8>("n", "").unwrap();
+    
+    assert_eq!(strict.parse(&["1", "2"]).unwrap_err().kind, CliErrorKind::UnexpectedPositional);
+    assert_eq!(strict.clone().switch("help", None, "").unwrap_err(),
+               CommandSpecError::DuplicateName { name: "--help".into() });
+    assert_eq!(strict.clone().variadic::<u8>("rest", "").unwrap().positional::<u8>("m", "").unwrap_err(),
+               CommandSpecError::PositionalAfterVariadic { name: "m".into() });
+    
+    let full =
+    (0..MAX_ARG_COUNT).try_fold(CommandSpec::new("full"), |s, i| s.switch(&format!("s{i}"), None, ""))
+                      .unwrap();
+    
+    assert_eq!(full.switch("last", None, "").unwrap_err(), CommandSpecError::TooManyFlags { name: "--last".into() });
+}
+
+#[test]
+fn test_help()
+{
+    let spec = spec();
+    
+    assert_eq!(spec.usage(), "usage: move [OPTIONS] <x> <y> [tags]...");
+    
+    let CliErrorKind::HelpRequested { help } = spec.parse(&["-h"]).unwrap_err().kind
+    else { panic!("expected help") };
+    
+    assert!(help.starts_with("Moves a sprite.\n\nusage: move"));
+    assert!(help.contains("\n  <x>  Horizontal target\n"));
+    assert!(help.contains("\n  <tags>  Tags to attach\n"));
+    assert!(help.contains("\n  -s, --speed <SPEED>  Movement speed [default: 1]\n"));
+    assert!(help.contains("\n      --label <TEXT>  Label to show\n"));
+    assert!(help.ends_with("\n  -v, --verbose  Prints each step\n  -q, --quiet  Prints nothing\n  -h, --help  Prints this help text"));
+}
@@ -0,0 +1,93 @@
+use crate::{Argument, ArgumentsBuilder, Coercion, CoerceError, MatchKind, OwnedArgument, Signature};
+
+#[cfg(no_std)]
+use alloc::string::String;
+
+#[test]
+fn test_exact()
+{
+    let arg = Argument::new_owned(String::from("exact"));
+    
+    assert_eq!(arg.coerce::<String>().as_deref(), Some("exact"));
+    assert_eq!(arg.coerce::<i64>(), None);
+}
+
+#[test]
+fn test_widening()
+{
+    let value = -5_i32;
+    
+    let arg = Argument::new_borrowed(&value);
+    
+    assert_eq!(arg.coerce::<i64>(), Some(-5));
+    assert_eq!(arg.coerce::<f64>(), Some(-5.0));
+    // Narrowing and sign changes are not lossless.
+    assert_eq!(arg.coerce::<i16>(), None);
+    assert_eq!(arg.coerce::<u64>(), None);
+    
+    let owned = OwnedArgument::new(1.5_f32);
+    
+    assert_eq!(owned.coerce::<f64>(), Some(1.5));
+    assert_eq!(owned.coerce::<i32>(), None);
+}
+
+#[test]
+fn test_lossy()
+{
+    let arg = Argument::new_owned(300_i64);
+    
+    assert_eq!(arg.coerce_lossy::<u16>(), Ok(300));
+    assert_eq!(arg.coerce_lossy::<u8>(), Err(CoerceError::Overflow));
+    assert_eq!(arg.coerce_lossy::<f32>(), Ok(300.0));
+    
+    let float = OwnedArgument::new(-2.75_f64);
+    
+    assert_eq!(float.coerce_lossy::<i8>(), Ok(-2));
+    assert_eq!(float.coerce_lossy::<u32>(), Err(CoerceError::Overflow));
+    assert_eq!(OwnedArgument::new(1e300_f64).coerce_lossy::<f32>(), Err(CoerceError::Overflow));
+    assert_eq!(OwnedArgument::new(f64::NAN).coerce_lossy::<i64>(), Err(CoerceError::Overflow));
+    assert_eq!(OwnedArgument::new(255.5_f32).coerce_lossy::<u8>(), Ok(255));
+    assert_eq!(OwnedArgument::new(256_f32).coerce_lossy::<u8>(), Err(CoerceError::Overflow));
+    
+    assert_eq!(OwnedArgument::new('c').coerce_lossy::<u32>(), Err(CoerceError::Mismatch));
+}
+
+#[test]
+fn test_extract()
+{
+    let args = ArgumentsBuilder::new().arg(3_i32).arg(String::from("name")).arg(2.5_f32).build();
+    
+    assert_eq!(args.extract::<(i32, String, f32)>(Coercion::Exact), Some((3, String::from("name"), 2.5)));
+    assert_eq!(args.extract::<(i64, String, f64)>(Coercion::Exact), None);
+    assert_eq!(args.extract::<(i64, String, f64)>(Coercion::Lossless), Some((3, String::from("name"), 2.5)));
+    assert_eq!(args.extract::<(u8, String, i32)>(Coercion::Lossless), None);
+    assert_eq!(args.extract::<(u8, String, i32)>(Coercion::Lossy), Some((3, String::from("name"), 2)));
+    assert_eq!(args.extract::<(i32, String)>(Coercion::Lossy), None);
+}
+
+#[test]
+fn test_signature_coercion()
+{
+    let args = ArgumentsBuilder::new().arg(3_i32).arg(300_i64).build();
+    
+    // Signatures only coerce once they opt into it.
+    let signature = Signature::new().param::<i64>().param::<i64>();
+    
+    assert_eq!(signature.coercion(), Coercion::Exact);
+    assert_eq!(signature.match_kind(&args), None);
+    assert!(signature.validate(&args).is_err());
+    
+    let signature = signature.with_coercion(Coercion::Lossless);
+    
+    assert_eq!(signature.match_kind(&args), Some(MatchKind::Coerced));
+    
+    // Lossy coercion still rejects values that do not fit.
+    let signature = Signature::new().param::<u8>().param::<u8>().with_coercion(Coercion::Lossy);
+    
+    assert_eq!(signature.match_kind(&args), None);
+    
+    let signature = Signature::new().param::<u8>().param::<u16>().with_coercion(Coercion::Lossy);
+    let coerced = signature.coerce_args(&args).unwrap();
+    
+    assert_eq!(coerced[1].downcast_ref::<u16>(), Some(&300));
+}
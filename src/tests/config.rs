@@ -0,0 +1,108 @@
+use crate::{Config, ConfigErrorKind, Schema, SchemaError, ValueSource, MAX_ARG_COUNT};
+
+#[cfg(no_std)]
+use alloc::{format, string::{String, ToString}};
+
+fn config() -> Config
+{
+    let schema =
+    Schema::new()
+        .key::<String>("name")
+        .key::<u16>("server.port")
+        .key::<bool>("server.tls");
+    
+    let mut config = Config::new(schema);
+    
+    config.set_default("server.port", 80u16).unwrap();
+    config.set_default("server.tls", false).unwrap();
+    
+    config
+}
+
+#[test]
+fn test_layers()
+{
+    let mut config = config();
+    
+    // Environment variables outrank files, regardless of load order.
+    config.load_vars("APP_", [("APP_SERVER__PORT", "9000"), ("APP_UNKNOWN", "x"), ("HOME", "/")]).unwrap();
+    
+    config.load_str("# Settings\nname = \"demo\"\n\n[server]\nport = 8080\ntls = true\n", "app.ini").unwrap();
+    
+    assert_eq!(config.get("name").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("demo"));
+    assert_eq!(config.get("server.port").and_then(|a| a.downcast_ref::<u16>()), Some(&9000));
+    assert_eq!(config.get("server.tls").and_then(|a| a.downcast_ref::<bool>()), Some(&true));
+    assert!(config.get("unknown").is_none());
+    
+    assert_eq!(config.source("server.port"), Some(&ValueSource::Env { variable: "APP_SERVER__PORT".into() }));
+    assert_eq!(config.source("server.tls"), Some(&ValueSource::File { name: "app.ini".into(), line: 6 }));
+    
+    config.set_override("server.port", 1u16).unwrap();
+    
+    assert_eq!(config.get("server.port").and_then(|a| a.downcast_ref::<u16>()), Some(&1));
+    assert_eq!(config.source("server.port"), Some(&ValueSource::Override));
+    assert_eq!(config.iter().count(), 3);
+}
+
+#[test]
+fn test_errors()
+{
+    let mut config = config();
+    
+    let error = config.load_str("name = a\nport 8080\n", "app.ini").unwrap_err();
+    
+    assert!(matches!(error.kind, ConfigErrorKind::Syntax));
+    assert_eq!(error.source, ValueSource::File { name: "app.ini".into(), line: 2 });
+    
+    let error = config.load_str("[server]\nhost = localhost\n", "app.ini").unwrap_err();
+    
+    assert!(matches!(error.kind, ConfigErrorKind::Schema(SchemaError::UnknownKey { .. })));
+    
+    let error = config.load_vars("APP_", [("APP_SERVER__PORT", "high")]).unwrap_err();
+    
+    assert!(matches!(error.kind, ConfigErrorKind::Schema(SchemaError::InvalidValue { .. })));
+    assert_eq!(error.source, ValueSource::Env { variable: "APP_SERVER__PORT".into() });
+    
+    // Values before the failure are kept, while the rest stay untouched.
+    assert_eq!(config.get("name").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("a"));
+    assert_eq!(config.source("server.port"), Some(&ValueSource::Default));
+    
+    // Defaults and overrides are checked against the schema.
+    let error = config.set_default("server.port", 80i32).unwrap_err();
+    
+    assert_eq!(error.to_string(), "default: invalid value for 'server.port': expected u16");
+    
+    let error = config.set_default("name", 1u8).unwrap_err();
+    
+    assert_eq!(error.to_string(), "default: invalid value for 'name': expected String");
+    
+    let error = config.set_override("server.host", "localhost").unwrap_err();
+    
+    assert!(matches!(error.kind, ConfigErrorKind::Schema(SchemaError::UnknownKey { .. })));
+    assert_eq!(error.source, ValueSource::Override);
+    assert_eq!(config.get("server.port").and_then(|a| a.downcast_ref::<u16>()), Some(&80));
+}
+
+#[test]
+fn test_capacity()
+{
+    let schema =
+    (0..=MAX_ARG_COUNT).fold(Schema::new(), |s, i| s.key::<u8>(&format!("k{i}")));
+    
+    let mut config = Config::new(schema);
+    
+    for i in 0..MAX_ARG_COUNT
+    {
+        config.set_default(&format!("k{i}"), 0u8).unwrap();
+    }
+    
+    let error = config.set_default(&format!("k{MAX_ARG_COUNT}"), 0u8).unwrap_err();
+    
+    assert!(matches!(error.kind, ConfigErrorKind::Capacity));
+    assert_eq!(config.iter().count(), MAX_ARG_COUNT);
+    
+    // Replacing a value still succeeds.
+    config.set_override("k0", 1u8).unwrap();
+    
+    assert_eq!(config.get("k0").and_then(|a| a.downcast_ref::<u8>()), Some(&1));
+}
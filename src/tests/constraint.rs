@@ -0,0 +1,86 @@
+use crate::{ArgumentsBuilder, Coercion, Constraint, Signature, SignatureError, Violation, ViolationKind};
+
+use core::ops::Bound;
+
+#[cfg(no_std)]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec
+};
+
+fn signature() -> Signature
+{
+    Signature::new().param::<i64>().named("count").constrain(Constraint::range(1_i64..=10))
+                    .param::<String>().named("mode").constrain(Constraint::one_of([String::from("fast"), String::from("slow")]))
+                    .variadic::<String>().named("tags").constrain(Constraint::non_empty_str())
+                                                       .constrain(Constraint::starts_with("#"))
+                    .with_coercion(Coercion::Lossless)
+}
+
+#[test]
+fn test_validate()
+{
+    let sig = signature();
+    
+    let args = ArgumentsBuilder::new().arg(5_i32).arg_str("fast").arg_str("#a").build();
+    assert_eq!(sig.validate(&args), Ok(()));
+    
+    let args = ArgumentsBuilder::new().arg(11_i64).arg_str("medium").arg_str("").arg(1_u8).build();
+    
+    let Err(SignatureError::Violations(violations)) = sig.validate(&args)
+    else { panic!("expected violations") };
+    
+    let positions : Vec<usize> = violations.iter().map(|v| v.position).collect();
+    
+    assert_eq!(positions, vec![0, 1, 2, 2, 3]);
+    assert_eq!(violations[3],
+               Violation
+               {
+                   position: 2,
+                   name: Some("tags".into()),
+                   kind: ViolationKind::Constraint { description: "starts with \"#\"".into() }
+               });
+    assert!(matches!(violations[4].kind, ViolationKind::Type { .. }));
+    assert_eq!(violations[0].to_string(), "argument 0 (count) is not in 1..=10");
+    
+    let args = ArgumentsBuilder::new().arg(1_i64).build();
+    assert_eq!(sig.validate(&args), Err(SignatureError::Arity { expected: 2, variadic: true, found: 1 }));
+}
+
+#[test]
+fn test_describe()
+{
+    let sig =
+    Signature::new().param::<u8>().named("level").constrain(Constraint::range(..5_u8))
+                    .param::<bool>()
+                    .param_any().named("items").constrain(Constraint::non_empty_slice::<i32>())
+                    .constrain(Constraint::custom("checked", |_| true));
+    
+    assert_eq!(sig.describe(), "level: u8 (in ..5)\nbool\nitems: _ (not empty, checked)");
+    assert_eq!(sig.to_string(), "(level: u8, bool, items: _)");
+    
+    // Only the module path of the outermost type is removed.
+    let paths = Signature::new().param::<String>().named("name").param::<Vec<String>>();
+    
+    assert_eq!(paths.describe(), "name: String\nVec<alloc::string::String>");
+    assert_eq!(paths.to_string(), "(name: String, Vec<alloc::string::String>)");
+    
+    let args = ArgumentsBuilder::new().arg(4_u8).arg(true).arg(Vec::<i32>::new()).build();
+    
+    assert!(sig.validate(&args).is_err());
+    
+    // Ranges excluding their start are written as comparisons.
+    let range = Constraint::range::<i64, _>((Bound::Excluded(0), Bound::Unbounded));
+    
+    assert_eq!(range.to_string(), "> 0");
+    assert_eq!(Constraint::range::<i64, _>((Bound::Excluded(0), Bound::Included(10))).to_string(), "> 0 and <= 10");
+    assert_eq!(Constraint::range::<i64, _>((Bound::Excluded(0), Bound::Excluded(10))).to_string(), "> 0 and < 10");
+    assert_eq!(Constraint::range(0_i64..).to_string(), "in 0..");
+    assert_eq!(Constraint::range::<i64, _>(..).to_string(), "in ..");
+    
+    let sig = Signature::new().param::<i64>().constrain(range);
+    
+    assert!(sig.validate(&ArgumentsBuilder::new().arg(-1_i64).build()).is_err());
+    assert!(sig.validate(&ArgumentsBuilder::new().arg(1_i64).build()).is_ok());
+}
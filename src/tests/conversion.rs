@@ -0,0 +1,68 @@
+use crate::{Argument, ConversionRegistry, OwnedArgument};
+
+use core::any::TypeId;
+
+#[cfg(no_std)]
+use alloc::string::String;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Id(u32);
+
+fn registry() -> ConversionRegistry
+{
+    let mut registry = ConversionRegistry::new();
+    
+    registry.register::<&'static str, String>(|v| v.downcast_ref::<&'static str>().map(|s| OwnedArgument::new(String::from(*s))));
+    registry.register::<String, u32>(|v| v.downcast_ref::<String>()?.parse::<u32>().ok().map(OwnedArgument::new));
+    registry.register::<u32, Id>(|v| v.downcast_ref::<u32>().map(|n| OwnedArgument::new(Id(*n))));
+    registry.register::<Id, u64>(|v| v.downcast_ref::<Id>().map(|id| OwnedArgument::new(u64::from(id.0))));
+    
+    registry
+}
+
+#[test]
+fn test_exact()
+{
+    let registry = registry();
+    
+    let converted = Argument::new_owned(5_u32).convert::<u32>(&registry).unwrap();
+    
+    assert_eq!(converted.value, 5);
+    assert!(converted.is_exact());
+}
+
+#[test]
+fn test_direct()
+{
+    let registry = registry();
+    
+    let converted = OwnedArgument::new(Id(9)).convert::<u64>(&registry).unwrap();
+    
+    assert_eq!(converted.value, 9);
+    assert_eq!(converted.steps.len(), 1);
+    assert_eq!(converted.steps[0].from(), TypeId::of::<Id>());
+    assert_eq!(converted.steps[0].to(), TypeId::of::<u64>());
+}
+
+#[test]
+fn test_chained()
+{
+    let mut registry = registry();
+    
+    let arg = Argument::new_owned("42");
+    
+    let converted = arg.convert::<Id>(&registry).unwrap();
+    
+    assert_eq!(converted.value, Id(42));
+    assert_eq!(converted.steps.len(), 3);
+    
+    // "42" -> String -> u32 -> Id -> u64 takes four steps.
+    assert_eq!(arg.convert::<u64>(&registry).map(|c| c.value), Some(42));
+    
+    registry.set_max_depth(3);
+    
+    assert!(arg.convert::<u64>(&registry).is_none());
+    
+    // A failing converter stops the chain.
+    assert!(Argument::new_owned("nan").convert::<Id>(&registry).is_none());
+}
@@ -0,0 +1,144 @@
+use crate::{Argument, Arguments, ArgumentsBatch, CsvErrorKind, CsvReader, CsvWriter, Schema, SchemaError, ToText};
+
+fn schema() -> Schema
+{
+    Schema::new()
+        .key::<String>("name")
+        .key::<u32>("age")
+        .key::<f64>("score")
+}
+
+#[test]
+fn test_round_trip()
+{
+    let input = "age,name,score\r\n30,\"Doe, Jane\",1.5\r\n\r\n41,\"say \"\"hi\"\"\nthere\",2\n";
+    
+    let mut reader = CsvReader::new(input.as_bytes(), schema());
+    
+    let rows : Vec<Arguments<'static>> = reader.by_ref().collect::<Result<_, _>>().unwrap();
+    
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][0].downcast_ref::<String>().map(String::as_str), Some("Doe, Jane"));
+    assert_eq!(rows[0][1].downcast_ref::<u32>(), Some(&30));
+    assert_eq!(rows[1][0].downcast_ref::<String>().map(String::as_str), Some("say \"hi\"\nthere"));
+    assert_eq!(rows[1][2].downcast_ref::<f64>(), Some(&2.0));
+    assert_eq!(reader.row(), 3);
+    
+    let mut writer = CsvWriter::new(Vec::new());
+    
+    writer.write_record(["name", "age", "score"]).unwrap();
+    
+    for row in &rows
+    {
+        writer.write_row(row).unwrap();
+    }
+    
+    let output = String::from_utf8(writer.into_inner()).unwrap();
+    
+    assert_eq!(output, "name,age,score\r\n\"Doe, Jane\",30,1.5\r\n\"say \"\"hi\"\"\nthere\",41,2\r\n");
+    
+    let mut reader = CsvReader::new(output.as_bytes(), schema());
+    let mut batch = reader.read_batch(8).unwrap().unwrap();
+    
+    assert_eq!(batch.rows(), 2);
+    assert_eq!(batch.column_mut::<u32>(1), Some(&mut [30, 41][..]));
+    assert!(reader.read_batch(8).unwrap().is_none());
+    
+    // A record whose only field is empty is quoted, so it is not read as a blank line.
+    let mut writer = CsvWriter::new(Vec::new());
+    
+    for field in ["a", "", "b"]
+    {
+        writer.write_record([field]).unwrap();
+    }
+    
+    let output = String::from_utf8(writer.into_inner()).unwrap();
+    
+    assert_eq!(output, "a\r\n\"\"\r\nb\r\n");
+    
+    let rows : Vec<Arguments<'static>> =
+    CsvReader::new(output.as_bytes(), Schema::new().key::<String>("text"))
+        .without_header()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    
+    let fields : Vec<&str> = rows.iter().map(|r| r[0].downcast_ref::<String>().unwrap().as_str()).collect();
+    
+    assert_eq!(fields, ["a", "", "b"]);
+}
+
+#[test]
+fn test_errors()
+{
+    let input = "name,age,score\nJane,x,1\nJoe,4\n\"Al\"x,5,1\nAl,5,1\n\"open,1,2";
+    
+    let mut reader = CsvReader::new(input.as_bytes(), schema());
+    
+    let error = reader.next().unwrap().unwrap_err();
+    
+    assert_eq!((error.row, error.column), (2, Some(2)));
+    assert!(matches!(error.kind, CsvErrorKind::Schema(SchemaError::InvalidValue { .. })));
+    
+    let error = reader.next().unwrap().unwrap_err();
+    
+    assert_eq!((error.row, error.column), (3, None));
+    assert!(matches!(error.kind, CsvErrorKind::FieldCount { expected: 3, found: 2 }));
+    
+    let error = reader.next().unwrap().unwrap_err();
+    
+    assert_eq!((error.row, error.column), (4, Some(1)));
+    assert!(matches!(error.kind, CsvErrorKind::StrayQuote));
+    
+    // Reading continues past invalid records.
+    assert!(reader.next().unwrap().is_ok());
+    
+    let error = reader.next().unwrap().unwrap_err();
+    
+    assert_eq!((error.row, error.column), (6, Some(1)));
+    assert!(matches!(error.kind, CsvErrorKind::UnclosedQuote));
+    assert!(reader.next().is_none());
+    
+    let error = CsvReader::new("name,age,rank\n".as_bytes(), schema()).next().unwrap().unwrap_err();
+    
+    assert_eq!((error.row, error.column), (1, Some(3)));
+    assert!(matches!(error.kind, CsvErrorKind::UnknownColumn { .. }));
+}
+
+#[test]
+fn test_headerless_and_fallback()
+{
+    let mut reader = CsvReader::new("Jane,30,1.5".as_bytes(), schema()).without_header();
+    
+    let row = reader.next().unwrap().unwrap();
+    
+    assert_eq!(row[1].downcast_ref::<u32>(), Some(&30));
+    assert!(reader.next().is_none());
+    
+    let row = Arguments::from_args(Vec::from([Argument::new_owned(Some(3u8))])).unwrap();
+    
+    let mut writer = CsvWriter::new(Vec::new());
+    
+    let error = writer.write_row(&row).unwrap_err();
+    
+    assert_eq!((error.row, error.column), (1, Some(1)));
+    assert!(matches!(error.kind, CsvErrorKind::Unsupported));
+    
+    writer.write_row_with(&row, |v| v.downcast_ref::<Option<u8>>().map(|o| o.map_or(String::new(), |n| n.to_text())))
+          .unwrap();
+    
+    assert_eq!(writer.into_inner(), b"3\r\n");
+    
+    let batch = ArgumentsBatch::new().with_column(Vec::from([1_u32, 2])).unwrap()
+                                     .with_column(Vec::from([Some(3_u8), None])).unwrap();
+    
+    let mut writer = CsvWriter::new(Vec::new());
+    
+    assert!(matches!(writer.write_batch(&batch).unwrap_err().kind, CsvErrorKind::Unsupported));
+    
+    let mut writer = CsvWriter::new(Vec::new());
+    
+    writer.write_batch_with(&batch, |v| v.downcast_ref::<Option<u8>>().map(|o| o.map_or(String::new(), |n| n.to_text())))
+          .unwrap();
+    
+    assert_eq!(writer.into_inner(), b"1,3\r\n2,\r\n");
+}
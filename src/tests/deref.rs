@@ -0,0 +1,75 @@
+use crate::{Argument, OwnedArgument};
+
+#[cfg(no_std)]
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec
+};
+
+#[cfg(not(no_std))]
+use std::{
+    borrow::Cow,
+    rc::Rc,
+    sync::Arc
+};
+
+#[test]
+fn test_sized()
+{
+    let args =
+    [
+        Argument::new_owned(5_i32),
+        Argument::new_owned(Box::new(5_i32)),
+        Argument::new_owned(Rc::new(5_i32)),
+        Argument::new_owned(Arc::new(5_i32)),
+        Argument::new_owned(Cow::<'static, i32>::Owned(5)),
+        Argument::new_owned(&5_i32)
+    ];
+    
+    for arg in args.iter()
+    {
+        assert_eq!(arg.downcast_deref::<i32>(), Some(&5));
+        assert_eq!(arg.downcast_deref::<i64>(), None);
+    }
+}
+
+#[test]
+fn test_str()
+{
+    let text = String::from("text");
+    
+    let args =
+    [
+        Argument::new_borrowed(&text),
+        Argument::new_owned("text"),
+        Argument::new_owned(Box::<str>::from("text")),
+        Argument::new_owned(Arc::<str>::from("text")),
+        Argument::new_owned(Cow::<'static, str>::Borrowed("text"))
+    ];
+    
+    for arg in args.iter()
+    {
+        assert_eq!(arg.downcast_deref::<str>(), Some("text"));
+    }
+    
+    assert_eq!(OwnedArgument::new(1_u8).downcast_deref::<str>(), None);
+}
+
+#[test]
+fn test_slice()
+{
+    let owned = OwnedArgument::new(vec![1_u8, 2, 3]);
+    
+    assert_eq!(owned.downcast_deref::<[u8]>(), Some(&[1, 2, 3][..]));
+    assert_eq!(owned.downcast_deref::<Vec<u8>>(), Some(&vec![1, 2, 3]));
+    assert_eq!(owned.downcast_deref::<[u16]>(), None);
+    
+    let boxed = OwnedArgument::new(Box::<[u8]>::from([4_u8, 5]));
+    
+    assert_eq!(boxed.downcast_deref::<[u8]>(), Some(&[4, 5][..]));
+}
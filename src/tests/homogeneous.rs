@@ -0,0 +1,113 @@
+use crate::{Argument, Arguments, ArgumentsBuilder, MAX_ARG_COUNT};
+
+use core::any::TypeId;
+
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec,
+    vec::Vec
+};
+
+#[test]
+fn test_from_homogeneous()
+{
+    let args = Arguments::from_homogeneous(vec![1.5_f64, 2.5, 3.0]).unwrap();
+    
+    assert!(args.iter().all(|a| a.is_owned()));
+    assert!(args.is_homogeneous());
+    assert_eq!(args.homogeneous_type(), Some(TypeId::of::<f64>()));
+    assert_eq!(args.as_homogeneous::<f64>(), Some(vec![&1.5, &2.5, &3.0]));
+    assert_eq!(args.as_homogeneous::<f32>(), None);
+    
+    let sum : f64 = args.into_homogeneous::<f64>().unwrap().into_iter().sum();
+    
+    assert_eq!(sum, 7.0);
+    
+    assert!(Arguments::from_homogeneous(vec![0_u8; MAX_ARG_COUNT + 1]).is_err());
+}
+
+#[test]
+fn test_contiguous_values()
+{
+    let values = vec![String::from("a"), String::from("b"), String::from("c")];
+    
+    let mut args = Arguments::from_homogeneous(values.clone()).unwrap();
+    
+    args[1].to_mut().downcast_mut::<String>().unwrap().push('d');
+    
+    let cloned = args.clone();
+    
+    assert_eq!(cloned.as_homogeneous::<String>(), Some(vec![&values[0], &String::from("bd"), &values[2]]));
+    
+    // Values can be moved out or dropped in any order.
+    let (first, rest) = args.split_first().unwrap();
+    
+    assert_eq!(first.downcast_owned::<String>().ok(), Some(String::from("a")));
+    
+    drop(cloned);
+    
+    assert_eq!(rest.into_homogeneous::<String>().unwrap(), ["bd", "c"]);
+    
+    let args = Arguments::from_homogeneous(vec![(); 3]).unwrap();
+    
+    assert_eq!(args.as_homogeneous::<()>(), Some(vec![&(), &(), &()]));
+}
+
+#[test]
+fn test_mixed()
+{
+    let text = String::from("text");
+    
+    let args = ArgumentsBuilder::new().arg(1_u8).arg_ref(&text).build();
+    
+    assert!(!args.is_homogeneous());
+    assert_eq!(args.homogeneous_type(), None);
+    assert!(args.as_homogeneous::<u8>().is_none());
+    
+    let args = args.into_homogeneous::<u8>().unwrap_err();
+    
+    assert_eq!(args.len(), 2);
+}
+
+#[test]
+fn test_borrowed_values()
+{
+    let values = [String::from("a"), String::from("b")];
+    
+    let args = ArgumentsBuilder::new().arg_ref(&values[0]).arg(values[1].clone()).build();
+    
+    assert_eq!(args.into_homogeneous::<String>().unwrap(), values);
+}
+
+#[test]
+fn test_cache_reset()
+{
+    let text = String::from("ab");
+    
+    let mut args = Arguments::from_homogeneous(vec![1_i32, 2]).unwrap();
+    
+    assert!(args.is_homogeneous());
+    
+    args[1] = Argument::new_owned(2_i64);
+    
+    assert!(!args.is_homogeneous());
+    assert!(args.as_homogeneous::<i32>().is_none());
+    
+    args[1] = Argument::new_owned(3_i32);
+    
+    assert_eq!(args.as_homogeneous::<i32>(), Some(vec![&1, &3]));
+    
+    // Borrowed slices share the type of String, but cannot be referenced as one.
+    let args = Arguments::from_args(vec![Argument::new_owned(String::from("a")), Argument::new_borrowed_str(&text)]).unwrap();
+    
+    assert_eq!(args.homogeneous_type(), Some(TypeId::of::<String>()));
+    assert!(args.as_homogeneous::<String>().is_none());
+    assert_eq!(args.into_homogeneous::<String>().unwrap(), ["a", "ab"]);
+    
+    let empty = Arguments::from_homogeneous(Vec::<u8>::new()).unwrap();
+    
+    assert!(empty.is_homogeneous());
+    assert_eq!(empty.homogeneous_type(), None);
+    assert_eq!(empty.as_homogeneous::<String>(), Some(vec![]));
+}
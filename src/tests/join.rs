@@ -0,0 +1,88 @@
+use crate::{Arguments, ArgumentsBuilder, CapacityError, MAX_ARG_COUNT};
+
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+fn numbers(args: &Arguments<'_>) -> Vec<u16>
+{
+    args.iter()
+        .map(|a| a.downcast_cloned::<u16>().unwrap())
+        .collect()
+}
+
+fn range(start: u16, end: u16) -> Arguments<'static>
+{
+    let mut builder = ArgumentsBuilder::new();
+    
+    for n in start..end
+    {
+        builder.insert_owned(n).unwrap();
+    }
+    
+    builder.build()
+}
+
+#[test]
+fn test_concat()
+{
+    let context = String::from("context");
+    
+    let front = ArgumentsBuilder::new().arg_ref(&context).build();
+    
+    let args = range(0, 3).prepend(front).unwrap();
+    
+    assert!(args[0].is_borrowed());
+    assert!(args[1].is_owned());
+    assert_eq!(args.len(), 4);
+    
+    let args = Arguments::concat(range(0, 2), range(2, 4)).unwrap()
+                         .append(range(4, 5)).unwrap();
+    
+    assert_eq!(numbers(&args), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_concat_overflow()
+{
+    let first = range(0, 1000);
+    let second = range(0, 30);
+    
+    let error = Arguments::concat(first, second).unwrap_err();
+    
+    assert_eq!(error.error, CapacityError { index: MAX_ARG_COUNT - 1000, rejected: 1030 - MAX_ARG_COUNT });
+    assert_eq!(error.first.len(), 1000);
+    assert_eq!(error.second.len(), 30);
+    
+    let error = range(0, 30).prepend(range(0, 1000)).unwrap_err();
+    
+    assert_eq!(error.error, CapacityError { index: MAX_ARG_COUNT - 1000, rejected: 1030 - MAX_ARG_COUNT });
+    assert_eq!(error.first.len(), 1000);
+    assert_eq!(error.second.len(), 30);
+}
+
+#[test]
+fn test_split()
+{
+    let (head, tail) = range(0, 5).split_at(2).unwrap();
+    
+    assert_eq!(numbers(&head), [0, 1]);
+    assert_eq!(numbers(&tail), [2, 3, 4]);
+    
+    assert_eq!(range(0, 2).split_at(3).unwrap_err().len(), 2);
+    
+    let (first, rest) = tail.split_first().unwrap();
+    
+    assert_eq!(first.downcast_cloned::<u16>(), Some(2));
+    assert_eq!(numbers(&rest), [3, 4]);
+    
+    let (last, rest) = rest.split_last().unwrap();
+    
+    assert_eq!(last.downcast_cloned::<u16>(), Some(4));
+    assert_eq!(numbers(&rest), [3]);
+    
+    assert!(range(0, 0).split_first().is_none());
+    assert!(range(0, 0).split_last().is_none());
+}
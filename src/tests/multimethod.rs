@@ -0,0 +1,87 @@
+use crate::{ArgumentsBuilder, DispatchError, MultiMethod};
+
+use core::any::TypeId;
+
+#[cfg(no_std)]
+use alloc::string::String;
+
+trait Entity
+{
+    fn name(&self) -> &'static str;
+}
+
+#[derive(Clone)]
+struct Ship;
+
+#[derive(Clone)]
+struct Asteroid;
+
+impl Entity for Ship
+{
+    fn name(&self) -> &'static str { "ship" }
+}
+
+impl Entity for Asteroid
+{
+    fn name(&self) -> &'static str { "asteroid" }
+}
+
+impl Entity for String
+{
+    fn name(&self) -> &'static str { "string" }
+}
+
+fn collide() -> MultiMethod<&'static str>
+{
+    let mut mm = MultiMethod::new(2);
+    
+    assert!(mm.register(&[TypeId::of::<Ship>(), TypeId::of::<Asteroid>()], |_| "ship hit asteroid").is_ok());
+    assert!(mm.register(&[TypeId::of::<dyn Entity>(), TypeId::of::<Asteroid>()],
+                        |args| args.cast::<dyn Entity>(0).unwrap().name()).is_ok());
+    assert!(mm.register(&[TypeId::of::<String>(), TypeId::of::<String>()], |_| "strings").is_ok());
+    
+    mm.register_cast::<Ship, dyn Entity>(|s| s);
+    mm.register_cast::<Asteroid, dyn Entity>(|a| a);
+    mm.register_cast::<String, dyn Entity>(|s| s);
+    
+    mm
+}
+
+#[test]
+fn test_dispatch()
+{
+    let mm = collide();
+    
+    let args = ArgumentsBuilder::new().arg(Ship).arg(Asteroid).build();
+    assert_eq!(mm.dispatch(&args), Ok("ship hit asteroid"));
+    
+    // Falls back to the cast of the first argument.
+    let args = ArgumentsBuilder::new().arg(Asteroid).arg(Asteroid).build();
+    assert_eq!(mm.dispatch(&args), Ok("asteroid"));
+    
+    // Borrowed strings are keyed as String.
+    let args = ArgumentsBuilder::new().arg_str("a").arg(String::from("b")).build();
+    assert_eq!(mm.dispatch(&args), Ok("strings"));
+    
+    // Casts from String apply to borrowed strings as well.
+    let args = ArgumentsBuilder::new().arg_str("rock").arg(Asteroid).build();
+    assert_eq!(mm.dispatch(&args), Ok("string"));
+    
+    let args = ArgumentsBuilder::new().arg(Asteroid).arg(Ship).build();
+    assert!(!mm.contains(&args));
+    assert_eq!(mm.dispatch(&args), Err(DispatchError::NoHandler));
+}
+
+#[test]
+fn test_default()
+{
+    let mut mm = collide();
+    
+    let args = ArgumentsBuilder::new().arg(Ship).build();
+    assert_eq!(mm.dispatch(&args), Err(DispatchError::TooFewArguments { expected: 2, found: 1 }));
+    
+    mm.set_default(|_| "default");
+    
+    assert_eq!(mm.dispatch(&args), Ok("default"));
+    assert!(mm.register(&[TypeId::of::<Ship>()], |_| "").is_err());
+}
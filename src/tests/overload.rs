@@ -0,0 +1,90 @@
+use crate::{ArgumentsBuilder, Coercion, Constraint, MatchKind, OverloadError, OverloadSet, Signature};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(no_std)]
+use alloc::{
+    format,
+    string::{String, ToString}
+};
+
+fn adder() -> OverloadSet<String>
+{
+    OverloadSet::new("add")
+        .with(Signature::new().param::<i64>().param::<i64>().with_coercion(Coercion::Lossless),
+              |a| (a[0].downcast_ref::<i64>().unwrap() + a[1].downcast_ref::<i64>().unwrap()).to_string())
+        .with(Signature::new().param::<f64>().param::<f64>().with_coercion(Coercion::Lossless),
+              |a| (a[0].downcast_ref::<f64>().unwrap() + a[1].downcast_ref::<f64>().unwrap()).to_string())
+        .with(Signature::new().param::<String>().param::<String>(),
+              |a| format!("{}{}", a[0].downcast_ref::<String>().unwrap(), a[1].downcast_ref::<String>().unwrap()))
+        .with(Signature::new().param::<String>().variadic_any(),
+              |a| format!("{} with {} more", a[0].downcast_ref::<String>().unwrap(), a.len() - 1))
+}
+
+#[test]
+fn test_resolution()
+{
+    let set = adder();
+    
+    let args = ArgumentsBuilder::new().arg(2_i64).arg(3_i64).build();
+    assert_eq!(set.call(&args).unwrap(), "5");
+    
+    // Only the f64 overload accepts both, by coercing i32 into f64.
+    let args = ArgumentsBuilder::new().arg(1.5_f64).arg(2_i32).build();
+    assert_eq!(set.call(&args).unwrap(), "3.5");
+    
+    // Borrowed strings are promoted into String.
+    let args = ArgumentsBuilder::new().arg_str("a").arg(String::from("b")).build();
+    assert_eq!(set.call(&args).unwrap(), "ab");
+    
+    let args = ArgumentsBuilder::new().arg(String::from("a")).arg(1_u8).arg(2_u8).build();
+    assert_eq!(set.call(&args).unwrap(), "a with 2 more");
+    
+    let sig = Signature::new().param::<String>().variadic_any();
+    assert_eq!(sig.match_kind(&args), Some(MatchKind::Variadic));
+}
+
+#[test]
+fn test_errors()
+{
+    let set = adder();
+    
+    let args = ArgumentsBuilder::new().arg(true).build();
+    let error = set.call(&args).unwrap_err();
+    
+    assert!(matches!(error, OverloadError::NoMatch { .. }));
+    assert_eq!(error.candidates().len(), 4);
+    
+    // Constraints are checked on the coerced arguments, both when resolving and calling.
+    let percent =
+    OverloadSet::new("percent")
+        .with(Signature::new().param::<i64>().constrain(Constraint::range::<i64, _>(0..=100)).with_coercion(Coercion::Lossless),
+              |a| a[0].downcast_ref::<i64>().unwrap().to_string());
+    
+    let args = ArgumentsBuilder::new().arg(50_i32).build();
+    assert_eq!(percent.call(&args).unwrap(), "50");
+    
+    let args = ArgumentsBuilder::new().arg(150_i32).build();
+    assert!(matches!(percent.call(&args), Err(OverloadError::NoMatch { .. })));
+    
+    // A constraint which only passes once rejects the arguments when they are coerced.
+    static CHECKS : AtomicUsize = AtomicUsize::new(0);
+    
+    let once =
+    OverloadSet::new("once")
+        .with(Signature::new().param::<i64>().constrain(Constraint::custom("unchecked", |_| CHECKS.fetch_add(1, Ordering::Relaxed) == 0)),
+              |_| ());
+    
+    let args = ArgumentsBuilder::new().arg(1_i64).build();
+    assert!(matches!(once.call(&args), Err(OverloadError::NoMatch { .. })));
+    assert_eq!(CHECKS.load(Ordering::Relaxed), 2);
+    
+    // u8 coerces into i64 and f64 equally well.
+    let args = ArgumentsBuilder::new().arg(1_u8).arg(2_u8).build();
+    let error = set.resolve(&args).unwrap_err();
+    
+    assert!(matches!(error, OverloadError::Ambiguous { .. }));
+    assert_eq!(error.candidates().len(), 2);
+    assert_eq!(error.to_string(),
+               "call to add is ambiguous; candidates: add(i64, i64) add(f64, f64)");
+}
@@ -0,0 +1,75 @@
+use crate::{Argument, Arguments, ArgumentsBuilder, KeywordArguments};
+
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec
+};
+
+fn detach(values: &[u32], label: &str) -> Arguments<'static>
+{
+    let mut builder = ArgumentsBuilder::new();
+    
+    builder.insert_borrowed(&values[0]);
+    builder.insert_borrowed_str(label);
+    builder.insert_owned(String::from("owned")).unwrap();
+    
+    builder.build().into_owned()
+}
+
+#[test]
+fn test_into_owned()
+{
+    let args =
+    {
+        let values = vec![7_u32];
+        let label = String::from("label");
+        
+        detach(&values, &label)
+    };
+    
+    assert!(args.iter().all(Argument::is_owned));
+    assert_eq!(args[0].downcast_cloned::<u32>(), Some(7));
+    assert_eq!(args[1].downcast_ref::<String>().map(|s| &s[..]), Some("label"));
+    assert_eq!(args[2].downcast_str(), Some("owned"));
+}
+
+#[test]
+fn test_into_static()
+{
+    let value = String::from("value");
+    
+    let arg : Argument<'static> = Argument::new_borrowed(&value).into_static();
+    
+    assert!(arg.is_owned());
+    assert_eq!(arg.downcast_str(), Some("value"));
+    
+    let mut kwargs = KeywordArguments::new();
+    kwargs.insert_borrowed("key", &value);
+    
+    let kwargs : KeywordArguments<'static> = kwargs.into_owned();
+    
+    assert!(kwargs.get("key").is_some_and(Argument::is_owned));
+}
+
+#[test]
+fn test_make_owned_where()
+{
+    let small = 1_u8;
+    let large = 1_000_u32;
+    let text = String::from("text");
+    
+    let mut builder = ArgumentsBuilder::new();
+    
+    builder.insert_borrowed(&small);
+    builder.insert_borrowed(&large);
+    builder.insert_borrowed(&text);
+    builder.insert_owned(2_u8).unwrap();
+    
+    assert_eq!(builder.make_owned_where(|a| a.is::<u8>() || a.is::<u32>()), 2);
+    
+    assert!(builder[0].is_owned());
+    assert!(builder[1].is_owned());
+    assert!(builder[2].is_borrowed());
+    assert!(builder[3].is_owned());
+}
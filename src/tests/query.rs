@@ -0,0 +1,79 @@
+use crate::{decode_query, encode_query, encode_query_with, KeywordArguments, QueryError, Schema, SchemaError};
+
+#[cfg(no_std)]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec
+};
+
+fn schema() -> Schema
+{
+    Schema::new()
+        .key::<String>("name")
+        .key::<u32>("page")
+        .key::<bool>("draft")
+        .repeated::<String>("tag")
+}
+
+#[test]
+fn test_round_trip()
+{
+    let mut kwargs = KeywordArguments::new();
+    
+    let _ = kwargs.insert_owned("name", String::from("a & b = 100%"));
+    let _ = kwargs.insert_owned("page", 3u32);
+    let _ = kwargs.insert_owned("tag", Vec::from([String::from("x y"), String::from("é")]));
+    let _ = kwargs.insert_owned("draft", false);
+    
+    let query = encode_query(&kwargs).unwrap();
+    
+    assert_eq!(query, "name=a+%26+b+%3D+100%25&page=3&tag=x+y&tag=%C3%A9&draft=false");
+    
+    let decoded = decode_query(&query, &schema()).unwrap();
+    
+    assert_eq!(decoded.keys().collect::<Vec<_>>(), ["name", "page", "tag", "draft"]);
+    assert_eq!(decoded.get("name").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("a & b = 100%"));
+    assert_eq!(decoded.get("page").and_then(|a| a.downcast_ref::<u32>()), Some(&3));
+    assert_eq!(decoded.get("draft").and_then(|a| a.downcast_ref::<bool>()), Some(&false));
+    assert_eq!(decoded.get("tag").and_then(|a| a.downcast_ref::<Vec<String>>()).map(Vec::len), Some(2));
+    assert!(decoded.iter().all(|(_, a)| a.is_owned()));
+    
+    // Repeated keys collect into a vector even with a single value.
+    let decoded = decode_query("tag=solo&&name", &schema()).unwrap();
+    
+    assert_eq!(decoded.get("tag").and_then(|a| a.downcast_ref::<Vec<String>>()), Some(&Vec::from([String::from("solo")])));
+    assert_eq!(decoded.get("name").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some(""));
+}
+
+#[test]
+fn test_unsupported()
+{
+    let mut kwargs = KeywordArguments::new();
+    
+    let _ = kwargs.insert_owned("point", (1u8, 2u8));
+    
+    assert_eq!(encode_query(&kwargs), Err(QueryError::Unsupported { key: "point".into() }));
+    
+    let query =
+    encode_query_with(&kwargs, |v| v.downcast_ref::<(u8, u8)>().map(|(x, y)| Vec::from([x.to_string(), y.to_string()])))
+        .unwrap();
+    
+    assert_eq!(query, "point=1&point=2");
+}
+
+fn decode_query_err(query: &str, schema: &Schema) -> Result<(), QueryError>
+{
+    decode_query(query, schema).map(drop)
+}
+
+#[test]
+fn test_decode_errors()
+{
+    let schema = schema();
+    
+    assert_eq!(decode_query_err("page=1&name=%4", &schema), Err(QueryError::InvalidEscape { position: 12 }));
+    assert_eq!(decode_query_err("name=%FF", &schema), Err(QueryError::InvalidUtf8 { position: 0 }));
+    assert_eq!(decode_query_err("page=x", &schema), Err(QueryError::Schema(SchemaError::InvalidValue { key: "page".into(), expected: "u32" })));
+    assert_eq!(decode_query_err("sort=asc", &schema), Err(QueryError::Schema(SchemaError::UnknownKey { key: "sort".into() })));
+    assert_eq!(decode_query_err("page=1&page=2", &schema), Err(QueryError::Schema(SchemaError::ValueCount { key: "page".into(), found: 2 })));
+}
@@ -0,0 +1,72 @@
+use crate::{CommandShell, CommandSpec, ShellError, ShellOutput, TokenizeError};
+
+#[cfg(no_std)]
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec
+};
+
+fn shell() -> CommandShell<String>
+{
+    let mut shell = CommandShell::new();
+    
+    let spec =
+    CommandSpec::new("move").positional::<i64>("x", "").unwrap()
+                            .positional::<i64>("y", "").unwrap()
+                            .flag_default::<f64>("speed", None, "", 1.0).unwrap();
+    
+    let registered =
+    shell.register(spec,
+                   |p| format!("{} {} at {}",
+                               p.positional[0].downcast_ref::<i64>().unwrap(),
+                               p.positional[1].downcast_ref::<i64>().unwrap(),
+                               p.flags.get("speed").and_then(|s| s.downcast_ref::<f64>()).unwrap()));
+    
+    assert!(registered.is_ok());
+    
+    let spec = CommandSpec::new("say").variadic::<String>("words", "").unwrap();
+    
+    assert!(shell.register(spec, |p| p.positional.iter_of::<String>().cloned().collect::<Vec<_>>().join("|")).is_ok());
+    assert!(shell.alias("move", "mv"));
+    
+    shell
+}
+
+#[test]
+fn test_tokenize()
+{
+    assert_eq!(CommandShell::<()>::tokenize(r#"say 'a b' "c \"d\"" e\ f --x="" '' "#).unwrap(),
+               vec!["say", "a b", "c \"d\"", "e f", "--x=", ""]);
+    assert_eq!(CommandShell::<()>::tokenize("say 'open"),
+               Err(TokenizeError::UnclosedQuote { position: 4 }));
+    assert_eq!(CommandShell::<()>::tokenize("say \\"), Err(TokenizeError::TrailingEscape));
+}
+
+#[test]
+fn test_eval()
+{
+    let shell = shell();
+    
+    assert_eq!(shell.eval("move 10 20 --speed 2.5"), Ok(ShellOutput::Value(String::from("10 20 at 2.5"))));
+    assert_eq!(shell.eval("mv 1 2"), Ok(ShellOutput::Value(String::from("1 2 at 1"))));
+    assert_eq!(shell.eval("say 'hello world' again"), Ok(ShellOutput::Value(String::from("hello world|again"))));
+    assert_eq!(shell.eval("   "), Ok(ShellOutput::Empty));
+    
+    assert!(matches!(shell.eval("list"), Ok(ShellOutput::Text(t)) if t.contains("move (aliases: mv)")));
+    assert!(matches!(shell.eval("help mv"), Ok(ShellOutput::Text(t)) if t.starts_with("usage: move")));
+    assert!(matches!(shell.eval("move --help"), Ok(ShellOutput::Text(t)) if t.starts_with("usage: move")));
+    
+    assert_eq!(shell.eval("jump"), Err(ShellError::UnknownCommand { name: "jump".into() }));
+    
+    let error = shell.eval("move 1 two").unwrap_err();
+    
+    assert_eq!(error.render(), "move: error: invalid value for y: expected i64 (token 1: 'two')\n  1 two\n    ^^^");
+    
+    let mut shell = shell;
+    
+    assert!(!shell.alias("say", "mv"));
+    assert!(!shell.alias("jump", "j"));
+    assert!(shell.register(CommandSpec::new("help"), |_| String::new()).is_err());
+}
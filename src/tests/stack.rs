@@ -0,0 +1,133 @@
+use crate::{Argument, ArgumentsStack, CapacityError, KeywordArguments, LayerDiff, MAX_ARG_COUNT};
+
+#[cfg(no_std)]
+use alloc::{
+    format,
+    string::String,
+    vec::Vec
+};
+
+fn defaults() -> KeywordArguments<'static>
+{
+    let mut defaults = KeywordArguments::new();
+    
+    let _ = defaults.insert_owned("color", String::from("red"));
+    let _ = defaults.insert_owned("size", 10i32);
+    let _ = defaults.insert_owned("bold", false);
+    
+    defaults
+}
+
+#[test]
+fn test_resolution()
+{
+    let defaults = defaults();
+    
+    let mut profile = KeywordArguments::new();
+    let _ = profile.insert_owned("size", 12i32);
+    
+    let mut call = KeywordArguments::new();
+    let _ = call.insert_owned("bold", true);
+    let _ = call.insert_owned("italic", true);
+    
+    let mut stack = ArgumentsStack::new();
+    
+    stack.push_borrowed("defaults", &defaults);
+    stack.push_layer("profile", profile);
+    stack.push_layer("call", call);
+    
+    assert_eq!(stack.get("size").and_then(|a| a.downcast_ref::<i32>()), Some(&12));
+    assert_eq!(stack.layer_name_of("size"), Some("profile"));
+    assert_eq!(stack.layer_of("color"), Some(0));
+    assert_eq!(stack.layer_name_of("bold"), Some("call"));
+    assert!(stack.get("missing").is_none());
+    
+    let flat = stack.flatten().unwrap();
+    let keys : Vec<&str> = flat.keys().collect();
+    
+    assert_eq!(keys, ["color", "size", "bold", "italic"]);
+    assert!(flat.iter().all(|(_, a)| a.is_borrowed()));
+    assert_eq!(flat.get("bold").and_then(|a| a.downcast_ref::<bool>()), Some(&true));
+    
+    drop(flat);
+    
+    // Lower layers stay borrowed until mutated.
+    assert!(stack.get("color").unwrap().is_borrowed());
+    
+    stack.get_mut("color").unwrap().to_mut().downcast_mut::<String>().unwrap().push_str("dish");
+    
+    assert!(stack.get("color").unwrap().is_owned());
+    assert_eq!(stack.get("color").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("reddish"));
+    assert_eq!(defaults.get("color").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("red"));
+    
+    let flat = stack.into_flattened().unwrap();
+    
+    assert_eq!(flat.len(), 4);
+    assert_eq!(flat.get("size").and_then(|a| a.downcast_ref::<i32>()), Some(&12));
+}
+
+#[test]
+fn test_set_and_diff()
+{
+    let mut stack = ArgumentsStack::new();
+    
+    assert!(stack.set("size", Argument::new_owned(1i32)).is_err());
+    
+    let mut override_ = KeywordArguments::new();
+    let _ = override_.insert_owned("size", 20i32);
+    let _ = override_.insert_owned("italic", true);
+    
+    stack = stack.with_layer("defaults", defaults()).with_layer("override", override_);
+    
+    assert!(stack.set("bold", Argument::new_owned(true)).is_ok());
+    assert_eq!(stack.layer_of("bold"), Some(1));
+    
+    let diff = stack.diff(0, 1).unwrap();
+    let keys : Vec<&str> = diff.iter().map(LayerDiff::key).collect();
+    
+    assert_eq!(keys, ["size", "italic", "bold", "color"]);
+    assert!(matches!(diff[0], LayerDiff::Overridden { .. }));
+    assert!(matches!(diff[1], LayerDiff::Added { .. }));
+    assert!(matches!(diff[3], LayerDiff::Removed { .. }));
+    
+    assert!(stack.diff(0, 2).is_none());
+    
+    let (name, top) = stack.pop_layer().unwrap();
+    
+    assert_eq!(&*name, "override");
+    assert_eq!(top.len(), 3);
+    assert_eq!(stack.get("size").and_then(|a| a.downcast_ref::<i32>()), Some(&10));
+}
+
+#[test]
+fn test_flatten_capacity()
+{
+    let layer =
+    |prefix: &str, count: usize|
+    {
+        let mut args = KeywordArguments::new();
+        
+        args.try_extend((0..count).map(|i| (format!("{prefix}{i}"), Argument::new_owned(String::from(prefix))))).unwrap();
+        args
+    };
+    
+    let mut top = layer("b", 600);
+    
+    // Keys of lower layers are still replaced past the limit, so they are not rejected.
+    let _ = top.insert_owned("a0", String::from("top"));
+    
+    let stack = ArgumentsStack::new().with_layer("lower", layer("a", 600)).with_layer("upper", top);
+    
+    let overflow = CapacityError { index: MAX_ARG_COUNT, rejected: 1200 - MAX_ARG_COUNT };
+    
+    assert_eq!(stack.flatten().unwrap_err(), overflow);
+    assert_eq!(stack.into_flattened().unwrap_err(), overflow);
+    
+    let mut flat = KeywordArguments::new();
+    
+    assert_eq!(flat.try_extend((0..1100).map(|i| (format!("k{i}"), Argument::new_owned(String::new())))),
+               Err(CapacityError { index: MAX_ARG_COUNT, rejected: 1100 - MAX_ARG_COUNT }));
+    assert!(flat.try_extend([("k0", Argument::new_owned(String::from("last")))]).is_ok());
+    assert_eq!(flat.len(), MAX_ARG_COUNT);
+    assert_eq!(flat.get("k0").and_then(|a| a.downcast_ref::<String>()).map(String::as_str), Some("last"));
+}
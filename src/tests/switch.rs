@@ -0,0 +1,68 @@
+use crate::{match_arg, Argument};
+
+#[cfg(no_std)]
+use alloc::string::{String, ToString};
+
+fn describe(arg: &Argument<'_>) -> String
+{
+    match_arg!(arg, {
+        i32 => |x| x.to_string(),
+        String => |s| s.clone(),
+        &'static str => |s| s.to_string(),
+        _ => String::from("unknown")
+    })
+}
+
+#[test]
+fn test_ref_switch()
+{
+    let text = String::from("borrowed");
+    
+    assert_eq!(describe(&Argument::new_owned(7_i32)), "7");
+    assert_eq!(describe(&Argument::new_borrowed(&text)), "borrowed");
+    assert_eq!(describe(&Argument::new_owned("static")), "static");
+    assert_eq!(describe(&Argument::new_owned(1.5_f32)), "unknown");
+}
+
+#[test]
+fn test_mut_switch()
+{
+    let value = 4_u64;
+    
+    let mut arg = Argument::new_borrowed(&value);
+    
+    match_arg!(&mut arg, {
+        u32 => |_x| unreachable!(),
+        u64 => |x| *x *= 2,
+        _ => unreachable!()
+    });
+    
+    // The write cloned the borrowed value first.
+    assert!(arg.is_owned());
+    assert_eq!(arg.downcast_cloned::<u64>(), Some(8));
+    assert_eq!(value, 4);
+}
+
+#[test]
+fn test_owned_switch()
+{
+    let arg = Argument::new_owned(String::from("moved"));
+    
+    let output =
+    match_arg!(arg, {
+        String => |s| s,
+        _ => |_other| unreachable!()
+    });
+    
+    assert_eq!(output, "moved");
+    
+    let value = 3_i16;
+    
+    let rest =
+    match_arg!(Argument::new_borrowed(&value), {
+        i32 => |_x| None,
+        _ => |other| Some(other)
+    });
+    
+    assert_eq!(rest.and_then(|a| a.downcast_cloned::<i16>()), Some(3));
+}
@@ -0,0 +1,85 @@
+use crate::{KeywordArguments, Template, TemplateError};
+
+#[cfg(no_std)]
+use alloc::{string::String, vec::Vec};
+
+#[test]
+fn test_render()
+{
+    let template = Template::compile("Hello {name}, you have {count:>5} messages").unwrap();
+    
+    let name = String::from("Ann");
+    
+    let mut kwargs = KeywordArguments::new();
+    
+    assert!(kwargs.insert_borrowed("name", &name));
+    assert!(kwargs.insert_owned("count", 3_u32).is_ok());
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "Hello Ann, you have     3 messages");
+    
+    // Rendering again does not parse the source.
+    kwargs.insert_owned("count", 12_u32).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "Hello Ann, you have    12 messages");
+}
+
+#[test]
+fn test_specs()
+{
+    let template = Template::compile("{a:+08.2}|{b:#x}|{c:*^7}|{d:.3}|{e:?}").unwrap();
+    
+    let mut kwargs = KeywordArguments::new();
+    
+    kwargs.insert_owned("a", 12.3456_f64).unwrap();
+    kwargs.insert_owned("b", 255_i32).unwrap();
+    kwargs.insert_owned("c", "mid").unwrap();
+    kwargs.insert_owned("d", String::from("truncated")).unwrap();
+    kwargs.insert_owned("e", 'q').unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "+0012.35|0xff|**mid**|tru|'q'");
+    
+    // NaN and infinite floats take neither the sign nor the zero padding.
+    kwargs.insert_owned("a", f64::NAN).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "     NaN|0xff|**mid**|tru|'q'");
+    
+    kwargs.insert_owned("a", f64::INFINITY).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "     inf|0xff|**mid**|tru|'q'");
+    
+    kwargs.insert_owned("a", f64::NEG_INFINITY).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "    -inf|0xff|**mid**|tru|'q'");
+}
+
+#[test]
+fn test_escapes()
+{
+    let template = Template::compile("{{literal}} {x}}}").unwrap();
+    
+    let mut kwargs = KeywordArguments::new();
+    kwargs.insert_owned("x", 1_u8).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap(), "{literal} 1}");
+    assert_eq!(template.placeholders().collect::<Vec<_>>(), ["x"]);
+}
+
+#[test]
+fn test_errors()
+{
+    assert_eq!(Template::compile("a {b").unwrap_err(), TemplateError::UnclosedPlaceholder { position: 2 });
+    assert_eq!(Template::compile("a } b").unwrap_err(), TemplateError::UnmatchedBrace { position: 2 });
+    assert_eq!(Template::compile("{:>4}").unwrap_err(), TemplateError::EmptyName { position: 0 });
+    assert_eq!(Template::compile("{x:!}").unwrap_err(), TemplateError::InvalidSpec { position: 0 });
+    
+    let template = Template::compile("{x} {y:x}").unwrap();
+    
+    let mut kwargs = KeywordArguments::new();
+    kwargs.insert_owned("x", 1_u8).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap_err(), TemplateError::MissingKey { name: "y".into() });
+    
+    kwargs.insert_owned("y", 1.5_f32).unwrap();
+    
+    assert_eq!(template.render(&kwargs).unwrap_err(), TemplateError::UnsupportedType { name: "y".into() });
+}
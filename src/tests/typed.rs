@@ -0,0 +1,96 @@
+use crate::{Arguments, ArgumentsBuilder};
+
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config
+{
+    verbose: bool
+}
+
+fn sample<'a>(name: &'a String, config: &'a Config) -> ArgumentsBuilder<'a>
+{
+    ArgumentsBuilder::new().arg(1_i32)
+                           .arg_ref(name)
+                           .arg(String::from("owned"))
+                           .arg(2_i32)
+                           .arg_ref(config)
+}
+
+#[test]
+fn test_lookups()
+{
+    let name = String::from("borrowed");
+    let config = Config { verbose: true };
+    
+    let args : Arguments<'_> = sample(&name, &config).build();
+    
+    assert_eq!(args.iter_of::<String>().collect::<Vec<_>>(), ["borrowed", "owned"]);
+    assert_eq!(args.find::<Config>(), Some(&config));
+    assert_eq!(args.position_of::<String>(), Some(1));
+    assert_eq!(args.position_of::<u8>(), None);
+    assert_eq!(args.count_of::<i32>(), 2);
+    assert_eq!(args.count_of::<u8>(), 0);
+}
+
+#[test]
+fn test_iter_of_mut()
+{
+    let name = String::from("borrowed");
+    let config = Config { verbose: true };
+    
+    let mut builder = sample(&name, &config);
+    
+    for s in builder.iter_of_mut::<String>()
+    {
+        s.push('!');
+    }
+    
+    // Only the matching borrowed argument became owned.
+    assert!(builder[1].is_owned());
+    assert!(builder[4].is_borrowed());
+    assert_eq!(builder.iter_of::<String>().collect::<Vec<_>>(), ["borrowed!", "owned!"]);
+    assert_eq!(name, "borrowed");
+}
+
+#[test]
+fn test_into_iter_of()
+{
+    let name = String::from("borrowed");
+    let config = Config { verbose: false };
+    
+    let strings : Vec<String> = sample(&name, &config).build().into_iter_of().collect();
+    
+    assert_eq!(strings, ["borrowed", "owned"]);
+    
+    let numbers : Vec<i32> = sample(&name, &config).into_iter_of().collect();
+    
+    assert_eq!(numbers, [1, 2]);
+}
+
+#[test]
+fn test_borrowed_str()
+{
+    let builder = ArgumentsBuilder::new().arg_str("text").arg(String::from("owned"));
+    
+    // Borrowed str is skipped alike, before and after building.
+    assert_eq!(builder.count_of::<String>(), 1);
+    assert_eq!(builder.position_of::<String>(), Some(1));
+    assert_eq!(builder.find::<String>().map(String::as_str), Some("owned"));
+    
+    let args = builder.build();
+    
+    assert_eq!(args.count_of::<String>(), 1);
+    assert_eq!(args.position_of::<String>(), Some(1));
+    assert_eq!(args.find::<String>().map(String::as_str), Some("owned"));
+    
+    let only = ArgumentsBuilder::new().arg_str("text").build();
+    
+    assert_eq!(only.position_of::<String>(), None);
+    assert_eq!(only.find::<String>(), None);
+    assert_eq!(only.into_iter_of::<String>().collect::<Vec<_>>(), ["text"]);
+}
@@ -0,0 +1,66 @@
+use crate::{Argument, Arguments, ArgumentsBuilder, ArgumentsRef};
+
+#[cfg(no_std)]
+use alloc::string::String;
+
+fn sum(args: ArgumentsRef<'_>) -> i64
+{
+    match args.split_first()
+    {
+        Some((first, rest)) => first.downcast_cloned::<i64>().unwrap_or(0) + sum(rest),
+        None => 0
+    }
+}
+
+fn sample() -> Arguments<'static>
+{
+    let mut builder = ArgumentsBuilder::new();
+    
+    builder.insert_owned(String::from("op")).unwrap();
+    
+    for n in 1..=4_i64
+    {
+        builder.insert_owned(n).unwrap();
+    }
+    
+    builder.build()
+}
+
+#[test]
+fn test_borrow_all()
+{
+    let args = sample();
+    
+    let borrowed = args.borrow_all();
+    
+    assert_eq!(borrowed.len(), args.len());
+    assert!(borrowed.iter().all(Argument::is_borrowed));
+    
+    // The borrowed entries point to the original contents.
+    let original = args[0].downcast_ref::<String>().unwrap();
+    let viewed = borrowed[0].downcast_ref::<String>().unwrap();
+    
+    assert!(core::ptr::eq(original, viewed));
+}
+
+#[test]
+fn test_view()
+{
+    let args = sample();
+    
+    let rest = args.view(1..).unwrap();
+    
+    assert_eq!(rest.len(), 4);
+    assert_eq!(sum(rest), 10);
+    assert_eq!(sum(rest.view(2..).unwrap()), 7);
+    assert_eq!(rest.view(..=1).map(sum), Some(3));
+    
+    assert!(args.view(2..9).is_none());
+    assert!(rest.view(5..).is_none());
+    
+    let copied = rest.view(..2).unwrap().to_arguments();
+    
+    assert_eq!(copied.len(), 2);
+    assert!(copied.iter().all(Argument::is_borrowed));
+    assert_eq!(sum(copied.as_view()), 3);
+}
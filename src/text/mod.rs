@@ -0,0 +1,7 @@
+mod render;
+mod to_text;
+
+#[cfg(not(no_std))]
+pub(crate) use render::render_builtin;
+pub(crate) use render::render_builtin_list;
+pub use to_text::ToText;
@@ -0,0 +1,87 @@
+#[cfg(no_std)]
+use alloc::{
+    string::String,
+    vec::Vec
+};
+
+use crate::Argument;
+
+use super::ToText;
+
+/// Invokes a macro with every primitive type rendered by the built-in table.
+macro_rules! builtins
+{
+    ($m:ident) =>
+    {
+        $m!(i8, i16, i32, i64, i128, isize,
+            u8, u16, u32, u64, u128, usize,
+            f32, f64, bool, char)
+    };
+}
+
+/// Renders an argument through the built-in ToText implementations.
+///
+/// Strings are read through Argument::downcast_str, which also covers borrowed strings.
+pub(crate) fn render_builtin(arg: &Argument<'_>) -> Option<String>
+{
+    if let Some(s) = arg.downcast_str()
+    {
+        return Some(s.to_text());
+    }
+    
+    macro_rules! render
+    {
+        ($($t:ty),*) =>
+        {
+            $(
+                if let Some(v) = arg.downcast_ref::<$t>()
+                {
+                    return Some(v.to_text());
+                }
+            )*
+        };
+    }
+    
+    builtins!(render);
+    
+    None
+}
+
+/// Renders an argument as a list of texts, one for each value.
+///
+/// Single values render as in render_builtin. Vectors and borrowed slices of the
+/// built-in types render as one text per element.
+pub(crate) fn render_builtin_list(arg: &Argument<'_>) -> Option<Vec<String>>
+{
+    if let Some(text) = render_builtin(arg)
+    {
+        return Some(Vec::from([text]));
+    }
+    
+    if let Some(v) = arg.downcast_ref::<Vec<String>>()
+    {
+        return Some(v.clone());
+    }
+    
+    if let Some(v) = arg.downcast_ref::<Vec<&'static str>>()
+    {
+        return Some(v.iter().map(ToText::to_text).collect());
+    }
+    
+    macro_rules! render_slice
+    {
+        ($($t:ty),*) =>
+        {
+            $(
+                if let Some(v) = arg.downcast_slice::<$t>()
+                {
+                    return Some(v.iter().map(ToText::to_text).collect());
+                }
+            )*
+        };
+    }
+    
+    builtins!(render_slice);
+    
+    None
+}
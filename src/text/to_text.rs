@@ -0,0 +1,72 @@
+#[cfg(no_std)]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::{String, ToString}
+};
+
+#[cfg(not(no_std))]
+use std::borrow::Cow;
+
+/// Renders a value as text, such as a CSV field, a query value or a command line argument.
+///
+/// bool renders as `true` or `false`, which parse back through FromStr.
+pub trait ToText
+{
+    /// Renders the value.
+    fn to_text(&self) -> String;
+}
+
+macro_rules! to_text_display
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl ToText for $t
+            {
+                #[inline(always)]
+                fn to_text(&self) -> String
+                {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+to_text_display!(i8, i16, i32, i64, i128, isize,
+                 u8, u16, u32, u64, u128, usize,
+                 f32, f64, bool, char, str, String);
+
+impl<T> ToText for &T
+where
+    T: ToText + ?Sized
+{
+    #[inline(always)]
+    fn to_text(&self) -> String
+    {
+        (**self).to_text()
+    }
+}
+
+impl<T> ToText for Box<T>
+where
+    T: ToText + ?Sized
+{
+    #[inline(always)]
+    fn to_text(&self) -> String
+    {
+        (**self).to_text()
+    }
+}
+
+impl<T> ToText for Cow<'_, T>
+where
+    T: ToText + ToOwned + ?Sized
+{
+    #[inline(always)]
+    fn to_text(&self) -> String
+    {
+        (**self).to_text()
+    }
+}